@@ -1,5 +1,5 @@
 //! This module provides idiomatic Rust implementations of fundamental algorithms,
-//! including mathematical functions (factorial, GCD), sequence computations (Fibonacci),
+//! including mathematical functions (factorial, GCD, Ackermann), sequence computations (Fibonacci),
 //! search algorithms (linear, binary), and the Tower of Hanoi puzzle.
 //!
 //! Each function is documented with:
@@ -102,6 +102,39 @@ pub fn fibonacci_iterative(n: u64) -> u64 {
     }
 }
 
+#[allow(dead_code)]
+/// Iterative computation of the nth element of the Fibonacci sequence, with overflow detection.
+///
+/// # Arguments
+/// * `n` - A non-negative integer.
+///
+/// # Returns
+/// `Some(F(n))`, or `None` if F(n) does not fit in a `u64` (from F(94) onwards).
+///
+/// # Complexity
+/// Linear: O(n), but at most 93 additions before the overflow is detected
+///
+/// # Example
+/// ```
+/// assert_eq!(fibonacci_checked(93), Some(12200160415121876738));
+/// assert_eq!(fibonacci_checked(94), None);
+/// ```
+///
+/// # See also
+/// [Fibonacci sequence - Wikipedia](https://en.wikipedia.org/wiki/Fibonacci_sequence)
+pub fn fibonacci_checked(n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+    let (mut prev, mut curr): (u64, u64) = (0, 1);
+    for _ in 2..=n {
+        let next = prev.checked_add(curr)?;
+        prev = curr;
+        curr = next;
+    }
+    Some(curr)
+}
+
 /// Recursive implementation to compute the nth element of the Fibonacci sequence.
 ///
 /// # Arguments
@@ -128,6 +161,169 @@ pub fn fibonacci_recursive(n: u64) -> u64 {
     }
 }
 
+#[allow(dead_code)]
+/// Iterator over the Fibonacci sequence: 0, 1, 1, 2, 3, 5, 8, ...
+///
+/// The iteration stops (returns `None`) after F(93), the largest Fibonacci number
+/// representable as a `u64`.
+///
+/// # Example
+/// ```
+/// let first_values: Vec<u64> = FibonacciIter::new().take(7).collect();
+/// assert_eq!(first_values, vec![0, 1, 1, 2, 3, 5, 8]);
+/// ```
+///
+/// # See also
+/// [Fibonacci sequence - Wikipedia](https://en.wikipedia.org/wiki/Fibonacci_sequence)
+pub struct FibonacciIter {
+    current: Option<u64>,
+    next: Option<u64>,
+}
+
+#[allow(dead_code)]
+impl FibonacciIter {
+    /// Creates an iterator starting at F(0) = 0.
+    pub fn new() -> Self {
+        FibonacciIter {
+            current: Some(0),
+            next: Some(1),
+        }
+    }
+}
+
+impl Default for FibonacciIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for FibonacciIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let value = self.current?;
+        // `next` becomes None as soon as the following sum overflows
+        self.current = self.next;
+        self.next = self.next.and_then(|next| value.checked_add(next));
+        Some(value)
+    }
+}
+
+/// Multiplies two 2x2 matrices modulo `modulus`.
+fn multiply_2x2_mod(a: &[[u64; 2]; 2], b: &[[u64; 2]; 2], modulus: u64) -> [[u64; 2]; 2] {
+    let m = modulus as u128;
+    let mut result = [[0u64; 2]; 2];
+    for i in 0..2 {
+        for j in 0..2 {
+            let sum = (a[i][0] as u128) * (b[0][j] as u128) + (a[i][1] as u128) * (b[1][j] as u128);
+            result[i][j] = (sum % m) as u64;
+        }
+    }
+    result
+}
+
+#[allow(dead_code)]
+/// Computes the nth element of the Fibonacci sequence modulo `modulus`.
+///
+/// Uses fast exponentiation of the matrix `[[1, 1], [1, 0]]`, whose nth power is
+/// `[[F(n+1), F(n)], [F(n), F(n-1)]]`. All intermediate values are reduced modulo
+/// `modulus`, so huge indices never overflow.
+///
+/// # Arguments
+/// * `n` - A non-negative integer.
+/// * `modulus` - The modulus (must be non-zero).
+///
+/// # Returns
+/// F(n) mod `modulus`.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+///
+/// # Complexity
+/// Logarithmic: O(log n)
+///
+/// # Example
+/// ```
+/// assert_eq!(fibonacci_mod(100, 1_000_000_007), 687995182);
+/// ```
+///
+/// # See also
+/// [Fibonacci sequence - Wikipedia](https://en.wikipedia.org/wiki/Fibonacci_sequence#Matrix_form)
+pub fn fibonacci_mod(n: u64, modulus: u64) -> u64 {
+    assert_ne!(
+        modulus, 0,
+        "Error in fibonacci_mod: modulus must be non-zero."
+    );
+
+    let mut result: [[u64; 2]; 2] = [[1 % modulus, 0], [0, 1 % modulus]];
+    let mut base: [[u64; 2]; 2] = [[1 % modulus, 1 % modulus], [1 % modulus, 0]];
+    let mut exponent = n;
+
+    // Binary exponentiation: square the base, multiply the result for each bit set
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply_2x2_mod(&result, &base, modulus);
+        }
+        base = multiply_2x2_mod(&base, &base, modulus);
+        exponent >>= 1;
+    }
+
+    result[0][1]
+}
+
+#[allow(dead_code)]
+/// Iterative implementation of the Ackermann function.
+///
+/// The function is defined recursively by:
+/// - `A(0, n) = n + 1`
+/// - `A(m, 0) = A(m - 1, 1)`
+/// - `A(m, n) = A(m - 1, A(m, n - 1))`
+///
+/// The recursion depth is as large as the result, which quickly overflows the native stack.
+/// Here, the pending outer calls are kept on an explicit `Vec` stack (only their `m`
+/// argument is needed), and the current inner value is carried in `n`.
+///
+/// # Arguments
+/// * `m`, `n` - Non-negative integers.
+///
+/// # Returns
+/// `A(m, n)`.
+///
+/// # Complexity
+/// Grows faster than any primitive recursive function: only small inputs are tractable
+/// (e.g. `m <= 3`, or `m = 4` with `n = 0`)
+///
+/// # Example
+/// ```
+/// assert_eq!(ackermann(2, 3), 9);
+/// assert_eq!(ackermann(3, 3), 61);
+/// ```
+///
+/// # See also
+/// [Ackermann function - Wikipedia](https://en.wikipedia.org/wiki/Ackermann_function)
+pub fn ackermann(m: u64, n: u64) -> u64 {
+    let mut stack: Vec<u64> = vec![m];
+    let mut n = n;
+
+    while let Some(m) = stack.pop() {
+        if m == 0 {
+            // A(0, n) = n + 1
+            n += 1;
+        } else if n == 0 {
+            // A(m, 0) = A(m - 1, 1)
+            stack.push(m - 1);
+            n = 1;
+        } else {
+            // A(m, n) = A(m - 1, A(m, n - 1)): compute the inner call first
+            stack.push(m - 1);
+            stack.push(m);
+            n -= 1;
+        }
+    }
+
+    n
+}
+
 /// Linear search in a slice of integers.
 ///
 /// # Arguments
@@ -149,7 +345,6 @@ pub fn fibonacci_recursive(n: u64) -> u64 {
 /// # See also
 /// [Linear search - Wikipedia](https://en.wikipedia.org/wiki/Linear_search)
 pub fn linear_search(slice: &[i32], target: i32) -> Option<usize> {
-    println!("Call to linear_search.");
     slice
         .iter()
         .enumerate()
@@ -178,7 +373,6 @@ pub fn generic_linear_search<T>(slice: &[T], target: T) -> Option<usize>
 where
     T: core::cmp::Eq,
 {
-    println!("Call to generic_linear_search.");
     //slice.iter().enumerate().find(|(_, &ref x)| *x == target).map(|(i, _)| i)
     slice
         .iter()
@@ -261,6 +455,53 @@ pub fn binary_search(
     }
 }
 
+#[allow(dead_code)]
+/// Finds two elements of a sorted slice summing to a target, with the two-pointer technique.
+///
+/// A left pointer starts at the beginning and a right pointer at the end: when the pair sum
+/// is too small the left pointer moves right, when it is too large the right pointer moves left.
+///
+/// # Arguments
+/// * `sorted` - A slice sorted in ascending order.
+/// * `target` - The expected sum.
+///
+/// # Returns
+/// `Some((i, j))` with `i < j` and `sorted[i] + sorted[j] == target`, `None` if no such pair exists.
+///
+/// # Panics
+/// Panics if the input slice is not sorted in ascending order.
+///
+/// # Complexity
+/// Linear: O(n)
+///
+/// # Example
+/// ```
+/// assert_eq!(two_sum_sorted(&[1, 2, 4, 7, 11], 9), Some((1, 3)));
+/// ```
+pub fn two_sum_sorted(sorted: &[i64], target: i64) -> Option<(usize, usize)> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    assert!(
+        is_array_sorted(sorted),
+        "(two_sum_sorted) Error: the slice is not sorted in ascending order (must be sorted first)."
+    );
+
+    let mut left = 0;
+    let mut right = sorted.len() - 1;
+    while left < right {
+        let sum = sorted[left] + sorted[right];
+        match sum.cmp(&target) {
+            std::cmp::Ordering::Equal => return Some((left, right)),
+            std::cmp::Ordering::Less => left += 1,
+            std::cmp::Ordering::Greater => right -= 1,
+        }
+    }
+
+    None
+}
+
 /// Represents a Tower of Hanoi game.
 struct HanoiGame {
     towers: [Vec<u32>; 3],
@@ -0,0 +1,794 @@
+//! Data Structures
+//!
+//! Generic data structures complementing the standard collections:
+//! - Indexed priority queue (binary min-heap with decrease-key)
+//! - Trie (prefix tree) over strings
+//! - LRU cache
+//! - Fenwick tree (binary indexed tree) for prefix sums
+//! - Binary search tree
+//! - Singly linked list
+//! - Ring buffer (fixed-capacity circular buffer)
+
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::Hash;
+
+/// Min-priority queue supporting priority decrease of a queued item.
+///
+/// Items are stored in a binary min-heap, and a HashMap gives the position of each
+/// item in the heap, so that an item can be found and moved up after its priority
+/// decreased. This is the operation needed by Dijkstra and A* algorithms, and missing
+/// from `std::collections::BinaryHeap`.
+///
+/// # Type Parameters
+/// * `S` - Item type (must implement Eq, Hash and Clone)
+/// * `P` - Priority type (must implement Ord)
+///
+/// # Example
+/// ```
+/// let mut queue = IndexedPriorityQueue::new();
+/// queue.push('a', 5);
+/// queue.push('b', 3);
+/// queue.decrease_priority(&'a', 1);
+/// assert_eq!(queue.pop_min(), Some(('a', 1)));
+/// ```
+///
+/// # Reference
+/// [Priority queue - Wikipedia](https://en.wikipedia.org/wiki/Priority_queue)
+#[derive(Clone, Debug)]
+pub struct IndexedPriorityQueue<S, P> {
+    heap: Vec<(S, P)>,
+    positions: HashMap<S, usize>,
+}
+
+impl<S, P> IndexedPriorityQueue<S, P>
+where
+    S: Eq + Hash + Clone,
+    P: Ord,
+{
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        IndexedPriorityQueue {
+            heap: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of queued items.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns `true` if `item` is in the queue.
+    pub fn contains(&self, item: &S) -> bool {
+        self.positions.contains_key(item)
+    }
+
+    /// Returns the priority of a queued item.
+    pub fn priority(&self, item: &S) -> Option<&P> {
+        self.positions
+            .get(item)
+            .map(|&position| &self.heap[position].1)
+    }
+
+    /// Adds an item to the queue, in O(log n).
+    ///
+    /// If the item is already queued, its priority is replaced.
+    pub fn push(&mut self, item: S, priority: P) {
+        if let Some(&position) = self.positions.get(&item) {
+            let is_decrease = priority < self.heap[position].1;
+            self.heap[position].1 = priority;
+            if is_decrease {
+                self.sift_up(position);
+            } else {
+                self.sift_down(position);
+            }
+            return;
+        }
+
+        let position = self.heap.len();
+        self.positions.insert(item.clone(), position);
+        self.heap.push((item, priority));
+        self.sift_up(position);
+    }
+
+    /// Returns the item with the lowest priority, without removing it.
+    pub fn peek_min(&self) -> Option<(&S, &P)> {
+        self.heap.first().map(|(item, priority)| (item, priority))
+    }
+
+    /// Removes and returns the item with the lowest priority, in O(log n).
+    pub fn pop_min(&mut self) -> Option<(S, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (item, priority) = self.heap.pop().unwrap();
+        self.positions.remove(&item);
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((item, priority))
+    }
+
+    /// Lowers the priority of a queued item, in O(log n).
+    ///
+    /// # Returns
+    /// `true` if the priority was updated, `false` if the item is not queued
+    /// or if `new_priority` is not lower than its current priority.
+    pub fn decrease_priority(&mut self, item: &S, new_priority: P) -> bool {
+        let position = match self.positions.get(item) {
+            Some(&position) => position,
+            None => return false,
+        };
+        if new_priority >= self.heap[position].1 {
+            return false;
+        }
+
+        self.heap[position].1 = new_priority;
+        self.sift_up(position);
+        true
+    }
+
+    /// Swaps two heap entries and updates their positions.
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.positions.insert(self.heap[i].0.clone(), i);
+        self.positions.insert(self.heap[j].0.clone(), j);
+    }
+
+    /// Moves an entry up until its parent has a lower or equal priority.
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if self.heap[parent].1 <= self.heap[position].1 {
+                break;
+            }
+            self.swap(parent, position);
+            position = parent;
+        }
+    }
+
+    /// Moves an entry down until its children have a greater or equal priority.
+    fn sift_down(&mut self, mut position: usize) {
+        let n = self.heap.len();
+        loop {
+            let left = 2 * position + 1;
+            let right = left + 1;
+            let mut smallest = position;
+            if left < n && self.heap[left].1 < self.heap[smallest].1 {
+                smallest = left;
+            }
+            if right < n && self.heap[right].1 < self.heap[smallest].1 {
+                smallest = right;
+            }
+            if smallest == position {
+                break;
+            }
+            self.swap(position, smallest);
+            position = smallest;
+        }
+    }
+}
+
+impl<S, P> Default for IndexedPriorityQueue<S, P>
+where
+    S: Eq + Hash + Clone,
+    P: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Node of a `Trie`: children keyed by the next character.
+#[derive(Clone, Debug, Default)]
+struct TrieNode {
+    children: BTreeMap<char, TrieNode>,
+    // The path from the root to this node is an inserted word
+    is_terminal: bool,
+}
+
+/// Prefix tree over strings, for membership and prefix queries.
+///
+/// Each edge is labeled by a character, and each inserted word is a path from the root
+/// to a node flagged as terminal. Children are kept in a BTreeMap, so that words are
+/// collected in sorted order.
+///
+/// # Example
+/// ```
+/// let mut trie = Trie::new();
+/// trie.insert("cat");
+/// trie.insert("car");
+/// assert_eq!(trie.words_with_prefix("ca"), vec!["car", "cat"]);
+/// ```
+///
+/// # Reference
+/// [Trie - Wikipedia](https://en.wikipedia.org/wiki/Trie)
+#[derive(Clone, Debug, Default)]
+pub struct Trie {
+    root: TrieNode,
+}
+
+impl Trie {
+    /// Creates an empty trie.
+    pub fn new() -> Self {
+        Trie {
+            root: TrieNode::default(),
+        }
+    }
+
+    /// Inserts a word, in O(length of the word).
+    pub fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_terminal = true;
+    }
+
+    /// Returns the node reached by following `prefix` from the root, if any.
+    fn find_node(&self, prefix: &str) -> Option<&TrieNode> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    /// Returns `true` if `word` was inserted (being a prefix of an inserted word is not enough).
+    pub fn contains(&self, word: &str) -> bool {
+        self.find_node(word).is_some_and(|node| node.is_terminal)
+    }
+
+    /// Returns all the inserted words starting with `prefix`, in sorted order.
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(node) = self.find_node(prefix) {
+            let mut current_word = prefix.to_string();
+            Self::collect_words(node, &mut current_word, &mut words);
+        }
+        words
+    }
+
+    /// Depth-first collection of the words below `node`, `current_word` being the path to `node`.
+    fn collect_words(node: &TrieNode, current_word: &mut String, words: &mut Vec<String>) {
+        if node.is_terminal {
+            words.push(current_word.clone());
+        }
+        for (&c, child) in node.children.iter() {
+            current_word.push(c);
+            Self::collect_words(child, current_word, words);
+            current_word.pop();
+        }
+    }
+}
+
+/// Key-value cache of bounded capacity, evicting the least recently used entry.
+///
+/// Each access stamps the entry with an increasing tick. A BTreeMap from ticks to keys
+/// keeps the entries in recency order, so the least recently used one is the first.
+///
+/// # Type Parameters
+/// * `K` - Key type (must implement Eq, Hash and Clone)
+/// * `V` - Value type (must implement Clone)
+///
+/// # Example
+/// ```
+/// let mut cache = LruCache::new(2);
+/// cache.put(1, "one");
+/// cache.put(2, "two");
+/// cache.get(&1);
+/// cache.put(3, "three"); // evicts 2
+/// assert_eq!(cache.get(&2), None);
+/// ```
+///
+/// # Reference
+/// [Cache replacement policies - Wikipedia](https://en.wikipedia.org/wiki/Cache_replacement_policies#LRU)
+#[derive(Clone, Debug)]
+pub struct LruCache<K, V> {
+    capacity: usize,
+    // Key -> (value, tick of the last access)
+    entries: HashMap<K, (V, u64)>,
+    // Tick of the last access -> key, oldest first
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Creates an empty cache holding at most `capacity` entries.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "Error in LruCache::new: capacity must be non-zero."
+        );
+        LruCache {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            recency: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of entries.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Marks an entry as the most recently used one.
+    fn touch(&mut self, key: &K) {
+        self.tick += 1;
+        if let Some((_, last_access)) = self.entries.get_mut(key) {
+            self.recency.remove(last_access);
+            *last_access = self.tick;
+            self.recency.insert(self.tick, key.clone());
+        }
+    }
+
+    /// Returns the value cached for `key`, marking it as the most recently used entry.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        if !self.entries.contains_key(key) {
+            return None;
+        }
+        self.touch(key);
+        self.entries.get(key).map(|(value, _)| value.clone())
+    }
+
+    /// Caches a value, evicting the least recently used entry if the cache is full.
+    ///
+    /// If `key` is already cached, its value is replaced and marked as the most recently used.
+    pub fn put(&mut self, key: K, value: V) {
+        if let Some((old_value, _)) = self.entries.get_mut(&key) {
+            *old_value = value;
+            self.touch(&key);
+            return;
+        }
+
+        if self.entries.len() == self.capacity {
+            if let Some((_, oldest_key)) = self.recency.pop_first() {
+                self.entries.remove(&oldest_key);
+            }
+        }
+
+        self.tick += 1;
+        self.recency.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, self.tick));
+    }
+}
+
+/// Fenwick tree (binary indexed tree) for prefix sums with point updates.
+///
+/// Internally 1-indexed: `tree[i]` holds the sum of the `i & (!i + 1)` values ending at
+/// index `i`, so that both updates and prefix sums walk O(log n) nodes.
+/// The public interface is 0-indexed.
+///
+/// # Example
+/// ```
+/// let mut tree = FenwickTree::from_slice(&[1, 2, 3, 4, 5]);
+/// assert_eq!(tree.prefix_sum(2), 6);
+/// tree.update(1, 10);
+/// assert_eq!(tree.range_sum(1, 3), 19);
+/// ```
+///
+/// # Reference
+/// [Fenwick tree - Wikipedia](https://en.wikipedia.org/wiki/Fenwick_tree)
+#[derive(Clone, Debug)]
+pub struct FenwickTree {
+    tree: Vec<i64>,
+}
+
+impl FenwickTree {
+    /// Creates a tree over `n` values, all zero.
+    pub fn new(n: usize) -> Self {
+        FenwickTree {
+            tree: vec![0; n + 1],
+        }
+    }
+
+    /// Creates a tree over the given values, in O(n).
+    pub fn from_slice(values: &[i64]) -> Self {
+        let n = values.len();
+        let mut tree = vec![0; n + 1];
+        tree[1..].copy_from_slice(values);
+        // Each node adds its partial sum to its parent
+        for i in 1..=n {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= n {
+                tree[parent] += tree[i];
+            }
+        }
+        FenwickTree { tree }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// Returns `true` if the tree holds no value.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to the value at `index`, in O(log n).
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn update(&mut self, index: usize, delta: i64) {
+        assert!(
+            index < self.len(),
+            "Error in FenwickTree::update: index out of bounds."
+        );
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            // Move to the next node covering index
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of the values at indices `0..=index`, in O(log n).
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn prefix_sum(&self, index: usize) -> i64 {
+        assert!(
+            index < self.len(),
+            "Error in FenwickTree::prefix_sum: index out of bounds."
+        );
+        let mut sum = 0;
+        let mut i = index + 1;
+        while i > 0 {
+            sum += self.tree[i];
+            // Remove the lowest set bit to move to the preceding range
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Returns the sum of the values at indices `l..=r`, in O(log n).
+    ///
+    /// # Panics
+    /// Panics if `l > r` or if `r` is out of bounds.
+    pub fn range_sum(&self, l: usize, r: usize) -> i64 {
+        assert!(l <= r, "Error in FenwickTree::range_sum: l must be <= r.");
+        if l == 0 {
+            self.prefix_sum(r)
+        } else {
+            self.prefix_sum(r) - self.prefix_sum(l - 1)
+        }
+    }
+}
+
+/// Node of a `BinarySearchTree`.
+struct BstNode<T> {
+    value: T,
+    left: Option<Box<BstNode<T>>>,
+    right: Option<Box<BstNode<T>>>,
+}
+
+/// Unbalanced binary search tree, holding a set of values.
+///
+/// Values smaller than a node are stored in its left subtree, greater ones in its right
+/// subtree. Duplicates are ignored. Operations run in O(height): O(log n) for random
+/// insertion orders, but O(n) for sorted ones.
+///
+/// # Type Parameters
+/// * `T` - Value type (must implement Ord)
+///
+/// # Example
+/// ```
+/// let mut tree = BinarySearchTree::new();
+/// for value in [5, 3, 8] {
+///     tree.insert(value);
+/// }
+/// assert_eq!(tree.in_order(), vec![&3, &5, &8]);
+/// ```
+///
+/// # Reference
+/// [Binary search tree - Wikipedia](https://en.wikipedia.org/wiki/Binary_search_tree)
+pub struct BinarySearchTree<T> {
+    root: Option<Box<BstNode<T>>>,
+    len: usize,
+}
+
+impl<T: Ord> BinarySearchTree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        BinarySearchTree { root: None, len: 0 }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a value.
+    ///
+    /// # Returns
+    /// `true` if the value was inserted, `false` if it was already in the tree (duplicates are ignored).
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                std::cmp::Ordering::Less => &mut node.left,
+                std::cmp::Ordering::Greater => &mut node.right,
+                std::cmp::Ordering::Equal => return false,
+            };
+        }
+        *link = Some(Box::new(BstNode {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.len += 1;
+        true
+    }
+
+    /// Returns `true` if `value` is in the tree.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                std::cmp::Ordering::Less => &node.left,
+                std::cmp::Ordering::Greater => &node.right,
+                std::cmp::Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Returns references to the values in sorted order (in-order traversal).
+    pub fn in_order(&self) -> Vec<&T> {
+        let mut values = Vec::with_capacity(self.len);
+        // Iterative traversal, the stack holding the nodes whose right subtree is pending
+        let mut stack: Vec<&BstNode<T>> = Vec::new();
+        let mut current = self.root.as_deref();
+        while current.is_some() || !stack.is_empty() {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_deref();
+            }
+            let node = stack.pop().unwrap();
+            values.push(&node.value);
+            current = node.right.as_deref();
+        }
+        values
+    }
+}
+
+impl<T: Ord> Default for BinarySearchTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for BinarySearchTree<T> {
+    /// Formats the values in sorted order, with the iterative traversal of `in_order`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_set().entries(self.in_order()).finish()
+    }
+}
+
+impl<T> Drop for BinarySearchTree<T> {
+    /// Drops the nodes iteratively: the default recursive drop could overflow the stack on degenerate trees.
+    fn drop(&mut self) {
+        let mut pending: Vec<Box<BstNode<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = pending.pop() {
+            pending.extend(node.left.take());
+            pending.extend(node.right.take());
+        }
+    }
+}
+
+/// Node of a `LinkedList`.
+#[derive(Debug)]
+struct ListNode<T> {
+    value: T,
+    next: Option<Box<ListNode<T>>>,
+}
+
+/// Singly linked list, in safe Rust.
+///
+/// # Type Parameters
+/// * `T` - Element type
+///
+/// # Example
+/// ```
+/// let mut list = LinkedList::new();
+/// list.push_front(2);
+/// list.push_front(1);
+/// list.reverse();
+/// assert_eq!(list.pop_front(), Some(2));
+/// ```
+///
+/// # Reference
+/// [Linked list - Wikipedia](https://en.wikipedia.org/wiki/Linked_list)
+#[derive(Debug)]
+pub struct LinkedList<T> {
+    head: Option<Box<ListNode<T>>>,
+    len: usize,
+}
+
+impl<T> LinkedList<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        LinkedList { head: None, len: 0 }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Adds an element at the front of the list, in O(1).
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(ListNode { value, next }));
+        self.len += 1;
+    }
+
+    /// Removes and returns the element at the front of the list, in O(1).
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Returns a reference to the element at the front of the list.
+    pub fn peek_front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Reverses the list in place, in O(n), by relinking the nodes (no allocation).
+    pub fn reverse(&mut self) {
+        let mut reversed: Option<Box<ListNode<T>>> = None;
+        let mut remaining = self.head.take();
+        while let Some(mut node) = remaining {
+            // Move the first remaining node to the front of the reversed list
+            remaining = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    /// Drops the nodes iteratively: the default recursive drop could overflow the stack on long lists.
+    fn drop(&mut self) {
+        let mut remaining = self.head.take();
+        while let Some(mut node) = remaining {
+            remaining = node.next.take();
+        }
+    }
+}
+
+/// Fixed-capacity circular buffer, keeping the most recent items.
+///
+/// Once the buffer is full, each push overwrites the oldest item, which makes it suited
+/// to statistics over a sliding window of a stream.
+///
+/// # Type Parameters
+/// * `T` - Item type
+///
+/// # Example
+/// ```
+/// let mut buffer = RingBuffer::new(2);
+/// buffer.push(1);
+/// buffer.push(2);
+/// buffer.push(3);
+/// assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&2, &3]);
+/// ```
+///
+/// # Reference
+/// [Circular buffer - Wikipedia](https://en.wikipedia.org/wiki/Circular_buffer)
+#[derive(Clone, Debug)]
+pub struct RingBuffer<T> {
+    items: Vec<T>,
+    capacity: usize,
+    // Index of the oldest item, once the buffer is full
+    start: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Creates an empty buffer holding at most `capacity` items.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "Error in RingBuffer::new: capacity must be non-zero."
+        );
+        RingBuffer {
+            items: Vec::with_capacity(capacity),
+            capacity,
+            start: 0,
+        }
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns `true` if the next push will overwrite the oldest item.
+    pub fn is_full(&self) -> bool {
+        self.items.len() == self.capacity
+    }
+
+    /// Returns the maximum number of items.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds an item, overwriting the oldest one if the buffer is full.
+    ///
+    /// # Returns
+    /// The overwritten item, if any.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if !self.is_full() {
+            self.items.push(item);
+            return None;
+        }
+        let oldest = std::mem::replace(&mut self.items[self.start], item);
+        self.start = (self.start + 1) % self.capacity;
+        Some(oldest)
+    }
+
+    /// Iterates over the items, from the oldest to the most recent.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let (newest, oldest) = self.items.split_at(self.start);
+        oldest.iter().chain(newest.iter())
+    }
+}
@@ -2,15 +2,20 @@
 //!
 //! A collection of file and directory operations for Rust.
 //! Includes functions for reading/writing text and binary files,
-//! listing directory contents, and retrieving file information.
+//! listing directory contents (with fuzzy file name search), and retrieving file information,
+//! as well as CSV parsing and writing, and hex dumps of binary content.
 //!
 //! Author: Vincent Espitalier
 //! Date: June 2024
 
+#![allow(dead_code)]
+
+use crate::misc::levenshtein_distance;
+use std::collections::VecDeque;
 use std::fs::File;
 use std::fs::{self, read_dir, Permissions};
 use std::io::ErrorKind;
-use std::io::{Read, Write};
+use std::io::{BufRead, BufReader, Read, Write};
 use std::path::Path;
 use std::time::SystemTime;
 
@@ -78,6 +83,64 @@ pub fn read_text_file_lines(file_path: &String, separator_opt: Option<&str>) ->
     content.split(separator).map(|s| s.to_string()).collect()
 }
 
+/// Reads the first lines of a text file.
+///
+/// The file is read lazily, so only the first `n` lines are loaded.
+///
+/// # Arguments
+/// * `file_path` - Path to the text file.
+/// * `n` - Number of lines to read.
+///
+/// # Returns
+/// The first `n` lines (or all the lines if the file is shorter), without line breaks.
+///
+/// # Panics
+/// Panics if the file cannot be found or read.
+///
+/// # Example
+/// ```
+/// let first_lines = head(&String::from("example.txt"), 3);
+/// ```
+pub fn head(file_path: &String, n: usize) -> Vec<String> {
+    let file = File::open(file_path).expect("File not found");
+    BufReader::new(file)
+        .lines()
+        .take(n)
+        .map(|line| line.expect("Error: Could not read file."))
+        .collect()
+}
+
+/// Reads the last lines of a text file.
+///
+/// The file is read line by line, keeping only the last `n` lines in memory,
+/// so that large files can be previewed.
+///
+/// # Arguments
+/// * `file_path` - Path to the text file.
+/// * `n` - Number of lines to read.
+///
+/// # Returns
+/// The last `n` lines (or all the lines if the file is shorter), without line breaks.
+///
+/// # Panics
+/// Panics if the file cannot be found or read.
+///
+/// # Example
+/// ```
+/// let last_lines = tail(&String::from("example.log"), 3);
+/// ```
+pub fn tail(file_path: &String, n: usize) -> Vec<String> {
+    let file = File::open(file_path).expect("File not found");
+    let mut last_lines: VecDeque<String> = VecDeque::with_capacity(n + 1);
+    for line in BufReader::new(file).lines() {
+        last_lines.push_back(line.expect("Error: Could not read file."));
+        if last_lines.len() > n {
+            last_lines.pop_front();
+        }
+    }
+    last_lines.into()
+}
+
 /// Writes content to a text file.
 ///
 /// # Arguments
@@ -99,6 +162,46 @@ pub fn write_text_file(file_path: &String, content: &String) {
     println!("File written: {}", file_path);
 }
 
+/// Writes content to a text file atomically.
+///
+/// The content is first written to a temporary file in the same directory, flushed to
+/// disk, then renamed over the target. On most filesystems the rename is atomic, so the
+/// target holds either its previous content or the new one, never a partial write.
+///
+/// # Arguments
+/// * `file_path` - Path to the output file.
+/// * `content` - Content to write to the file.
+///
+/// # Returns
+/// `Ok(())` on success, the I/O error otherwise (the temporary file is then removed).
+///
+/// # Example
+/// ```
+/// write_text_file_atomic(&String::from("config.txt"), &String::from("key=value")).unwrap();
+/// ```
+pub fn write_text_file_atomic(file_path: &String, content: &String) -> std::io::Result<()> {
+    let path = Path::new(file_path);
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::new(ErrorKind::InvalidInput, "Invalid file path."))?;
+
+    // The temporary file must be on the same filesystem for the rename to be atomic
+    let mut temp_file_name = file_name.to_os_string();
+    temp_file_name.push(format!(".tmp.{}", std::process::id()));
+    let temp_path = path.with_file_name(temp_file_name);
+
+    let result = File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
 /// Writes a vector of strings to a text file, one string per line.
 ///
 /// # Arguments
@@ -192,6 +295,41 @@ pub fn list_directory(dir_path: &String) -> Vec<String> {
     }
 }
 
+/// Finds the directory entries whose name is close to a query, by edit distance.
+///
+/// The query is compared to the base name of each entry (its file name without extension),
+/// so that `maze1` matches `maze1.txt` exactly.
+///
+/// # Arguments
+/// * `dir_path` - Path to the directory as a string.
+/// * `query` - The (possibly misspelled) name to look for.
+/// * `max_distance` - Maximum Levenshtein distance between the query and a base name.
+///
+/// # Returns
+/// The paths of the matching entries (as returned by `list_directory`), by ascending
+/// distance, then by path.
+///
+/// # Panics
+/// Panics if the directory cannot be found or read.
+///
+/// # Example
+/// ```
+/// let mazes = find_similar_filenames(&String::from("divers/labyrinthes"), "labyrinth1", 1);
+/// ```
+pub fn find_similar_filenames(dir_path: &String, query: &str, max_distance: usize) -> Vec<String> {
+    let mut matches: Vec<(usize, String)> = list_directory(dir_path)
+        .into_iter()
+        .filter_map(|path| {
+            let base_name = Path::new(&path).file_stem()?.to_string_lossy().into_owned();
+            let distance = levenshtein_distance(&base_name, query);
+            (distance <= max_distance).then_some((distance, path))
+        })
+        .collect();
+
+    matches.sort();
+    matches.into_iter().map(|(_, path)| path).collect()
+}
+
 /// Enum representing different file types.
 #[derive(Debug)]
 pub enum FileType {
@@ -278,3 +416,165 @@ pub fn get_file_info(file_path: &String) -> FileInfo {
 pub fn get_file_size(file_path: &String) -> u64 {
     fs::metadata(file_path).expect("File not found.").len()
 }
+
+/// Parses CSV content into rows of fields.
+///
+/// Fields may be enclosed in double quotes, in which case they can contain the delimiter,
+/// line breaks, and double quotes (escaped by doubling them: `""`).
+/// Line breaks may be "\n" or "\r\n"; a final line break does not start a new row.
+///
+/// # Arguments
+/// * `content` - The CSV text.
+/// * `delimiter` - The field delimiter (usually ',' or ';').
+///
+/// # Returns
+/// A vector of rows, each row being a vector of (unquoted) fields.
+///
+/// # Example
+/// ```
+/// let rows = parse_csv("a,\"b,c\",d\n", ',');
+/// assert_eq!(rows, vec![vec!["a", "b,c", "d"]]);
+/// ```
+///
+/// # Reference
+/// [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180)
+pub fn parse_csv(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    // Whether a character was consumed since the last line break
+    let mut row_started = false;
+
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        row_started = true;
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    // Escaped quote
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' {
+            in_quotes = true;
+        } else if c == delimiter {
+            row.push(std::mem::take(&mut field));
+        } else if c == '\n' || (c == '\r' && chars.peek() == Some(&'\n')) {
+            if c == '\r' {
+                chars.next();
+            }
+            row.push(std::mem::take(&mut field));
+            rows.push(std::mem::take(&mut row));
+            row_started = false;
+        } else {
+            field.push(c);
+        }
+    }
+
+    // Last row, when the content does not end with a line break
+    if row_started {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Formats rows of fields as CSV content.
+///
+/// Fields containing the delimiter, a double quote or a line break are enclosed in
+/// double quotes, with inner double quotes doubled, so that `parse_csv` gives back the rows.
+///
+/// # Arguments
+/// * `rows` - The rows of fields.
+/// * `delimiter` - The field delimiter.
+///
+/// # Returns
+/// The CSV text, each row ending with "\n".
+///
+/// # Example
+/// ```
+/// let rows = vec![vec![String::from("a"), String::from("b,c")]];
+/// assert_eq!(write_csv(&rows, ','), "a,\"b,c\"\n");
+/// ```
+pub fn write_csv(rows: &[Vec<String>], delimiter: char) -> String {
+    let mut content = String::new();
+    for row in rows {
+        let fields: Vec<String> = row
+            .iter()
+            .map(|field| {
+                let needs_quotes = field.contains(delimiter)
+                    || field.contains('"')
+                    || field.contains('\n')
+                    || field.contains('\r');
+                if needs_quotes {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field.clone()
+                }
+            })
+            .collect();
+        content.push_str(&fields.join(&delimiter.to_string()));
+        content.push('\n');
+    }
+    content
+}
+
+/// Formats binary content as a hex dump, in the style of `xxd` or `hexdump -C`.
+///
+/// Each line shows the offset of its first byte (8 hexadecimal digits), the bytes in
+/// hexadecimal, and an ASCII gutter where non-printable bytes are shown as `.`.
+/// The hexadecimal column of the last line is padded, so that the gutters are aligned.
+///
+/// # Arguments
+/// * `bytes` - The content to dump (e.g. returned by `read_binary_file`).
+/// * `bytes_per_line` - Number of bytes shown on each line.
+///
+/// # Returns
+/// The dump, one line per `bytes_per_line` bytes, each line ending with a newline.
+///
+/// # Panics
+/// Panics if `bytes_per_line` is zero.
+///
+/// # Example
+/// ```
+/// assert_eq!(hex_dump(b"Hi!\n", 4), "00000000: 48 69 21 0a  Hi!.\n");
+/// ```
+pub fn hex_dump(bytes: &[u8], bytes_per_line: usize) -> String {
+    assert_ne!(
+        bytes_per_line, 0,
+        "Error in hex_dump: bytes_per_line must be non-zero."
+    );
+    let hex_width: usize = 3 * bytes_per_line - 1;
+
+    let mut dump = String::new();
+    for (line_index, line_bytes) in bytes.chunks(bytes_per_line).enumerate() {
+        let hex: Vec<String> = line_bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let ascii: String = line_bytes
+            .iter()
+            .map(|&byte| {
+                if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        dump.push_str(&format!(
+            "{:08x}: {:<hex_width$}  {}\n",
+            line_index * bytes_per_line,
+            hex.join(" "),
+            ascii
+        ));
+    }
+    dump
+}
@@ -1,12 +1,16 @@
 //! SVG and Fractal Generation Utilities
 //!
 //! A collection of tools for creating SVG graphics and fractal images.
-//! Includes implementations for the Koch snowflake and Mandelbrot-like fractals.
+//! Includes implementations for the Koch snowflake and Mandelbrot-like fractals,
+//! with image (BMP, PGM) and raw value (CSV) export.
 //!
 //! Author: Vincent Espitalier
 //! Date: June 2024
 
+#![allow(dead_code)]
+
 use crate::files;
+use crate::misc::{clamp, lerp};
 use std::cmp::min;
 use std::fmt::Write;
 
@@ -144,18 +148,18 @@ pub fn koch_snowflake_recursive(lines: &Vec<Line>, n_iter: u32) -> Vec<Line> {
         // Transform each segment into 4 segments
         let x1 = line.x1;
         let y1 = line.y1;
-        let x2 = (2. / 3. * (line.x1 as f32) + 1. / 3. * (line.x2 as f32)) as u32;
-        let y2 = (2. / 3. * (line.y1 as f32) + 1. / 3. * (line.y2 as f32)) as u32;
+        let x2 = lerp(line.x1 as f64, line.x2 as f64, 1. / 3.) as u32;
+        let y2 = lerp(line.y1 as f64, line.y2 as f64, 1. / 3.) as u32;
 
-        let x4 = (1. / 3. * (line.x1 as f32) + 2. / 3. * (line.x2 as f32)) as u32;
-        let y4 = (1. / 3. * (line.y1 as f32) + 2. / 3. * (line.y2 as f32)) as u32;
+        let x4 = lerp(line.x1 as f64, line.x2 as f64, 2. / 3.) as u32;
+        let y4 = lerp(line.y1 as f64, line.y2 as f64, 2. / 3.) as u32;
         let x5 = line.x2;
         let y5 = line.y2;
 
         let dx24: i32 = (sqrt_3_over_2 * ((x4 as i32 - x2 as i32) as f32)) as i32;
         let dy24: i32 = (sqrt_3_over_2 * ((y4 as i32 - y2 as i32) as f32)) as i32;
-        let mx24 = (x4 + x2) / 2;
-        let my24 = (y4 + y2) / 2;
+        let mx24 = lerp(x2 as f64, x4 as f64, 0.5) as u32;
+        let my24 = lerp(y2 as f64, y4 as f64, 0.5) as u32;
         let x3 = (mx24 as i32 + dy24) as u32;
         let y3 = (my24 as i32 - dx24) as u32;
 
@@ -304,17 +308,116 @@ fn calculate_internal_fractal(
                     break;
                 }
             }
-            norm = (2. - norm) / 2.;
-            if norm < 0. {
-                norm = 0.;
-            }
-            pixels.push(norm);
+            // Map the norm from [0, 2] (and beyond, for escaped points) to a value in [0, 1]
+            pixels.push(clamp((2. - norm) / 2., 0., 1.));
         }
     }
 
     pixels
 }
 
+/// Parameters of a fractal rendering: viewport, resolution and iteration count.
+#[derive(Clone, Debug)]
+pub struct FractalParams {
+    pub x_min: f64,
+    pub x_max: f64,
+    pub y_min: f64,
+    pub y_max: f64,
+    pub width: u32,
+    pub height: u32,
+    pub max_n_iter: usize,
+    pub x_fractal: f64,
+    pub y_fractal: f64,
+}
+
+impl FractalParams {
+    /// Creates rendering parameters with the default viewport [-1, 1] x [-1, 1],
+    /// a 640x480 resolution and 50 iterations.
+    ///
+    /// # Arguments
+    /// * `x_fractal`, `y_fractal` - Fractal parameters.
+    ///
+    /// # Example
+    /// ```
+    /// // Zoom on the upper right quarter
+    /// let params = FractalParams { x_min: 0., y_min: 0., ..FractalParams::new(0.3, 0.5) };
+    /// ```
+    pub fn new(x_fractal: f64, y_fractal: f64) -> Self {
+        FractalParams {
+            x_min: -1.,
+            x_max: 1.,
+            y_min: -1.,
+            y_max: 1.,
+            width: 640,
+            height: 480,
+            max_n_iter: 50,
+            x_fractal,
+            y_fractal,
+        }
+    }
+}
+
+/// Calculates a fractal pattern over a configurable viewport.
+///
+/// # Arguments
+/// * `params` - Viewport, resolution, iteration count and fractal parameters.
+///
+/// # Returns
+/// A vector of `width * height` values in [0, 1], row by row.
+///
+/// # Example
+/// ```
+/// let pixels = render_fractal(FractalParams::new(0.3, 0.5));
+/// ```
+pub fn render_fractal(params: FractalParams) -> Vec<f64> {
+    calculate_internal_fractal(
+        params.x_min,
+        params.x_max,
+        params.width,
+        params.y_min,
+        params.y_max,
+        params.height,
+        params.max_n_iter,
+        params.x_fractal,
+        params.y_fractal,
+    )
+}
+
+/// Reorders a pixel buffer in boustrophedon (serpentine) order, for displays expecting it.
+///
+/// Rows of even index (0, 2, ...) are kept left to right, rows of odd index are reversed.
+/// The transform is its own inverse: applying it twice restores the original buffer.
+///
+/// # Arguments
+/// * `pixels` - Pixel values, row by row (as returned by `render_fractal`).
+/// * `width` - Width of the image in pixels.
+///
+/// # Returns
+/// The pixels in serpentine order.
+///
+/// # Panics
+/// Panics if `width` is zero or does not divide the number of pixels.
+///
+/// # Example
+/// ```
+/// assert_eq!(to_boustrophedon(&[1., 2., 3., 4.], 2), vec![1., 2., 4., 3.]);
+/// ```
+///
+/// # Reference
+/// [Boustrophedon - Wikipedia](https://en.wikipedia.org/wiki/Boustrophedon)
+pub fn to_boustrophedon(pixels: &[f64], width: u32) -> Vec<f64> {
+    assert!(
+        width > 0 && pixels.len().is_multiple_of(width as usize),
+        "Error in to_boustrophedon: the number of pixels must be a multiple of the width"
+    );
+
+    let mut reordered: Vec<f64> = pixels.to_vec();
+    for row in reordered.chunks_mut(width as usize).skip(1).step_by(2) {
+        row.reverse();
+    }
+    reordered
+}
+
 /// Converts a hexadecimal string with space-separated bytes to a byte vector.
 ///
 /// # Arguments
@@ -352,13 +455,6 @@ fn convert_hex_string_to_vec(hex_string: &str) -> Vec<u8> {
 pub fn calculate_fractal_and_write_bmp(x_fractal: f64, y_fractal: f64, bmp_file_path: &String) {
     let mut bmp_bytes: Vec<u8> = Vec::new();
 
-    let x_min = -1.;
-    let x_max = 1.;
-    let y_min = -1.;
-    let y_max = 1.;
-
-    let max_n_iter: usize = 50;
-
     // BMP header (640x480) - Hardcoded resolution (Fixed in the BMP header)
     // TODO: Find a way to write a matrix image without external libraries,
     //       allowing custom parameters (resolution) etc.
@@ -373,9 +469,11 @@ pub fn calculate_fractal_and_write_bmp(x_fractal: f64, y_fractal: f64, bmp_file_
     bmp_bytes.append(&mut convert_hex_string_to_vec(bmp_header3));
     bmp_bytes.append(&mut convert_hex_string_to_vec(bmp_header4));
 
-    let img_pixels: Vec<f64> = calculate_internal_fractal(
-        x_min, x_max, width, y_min, y_max, height, max_n_iter, x_fractal, y_fractal,
-    );
+    let img_pixels: Vec<f64> = render_fractal(FractalParams {
+        width,
+        height,
+        ..FractalParams::new(x_fractal, y_fractal)
+    });
 
     for x_index in 0..width {
         for y_index in 0..height {
@@ -390,3 +488,98 @@ pub fn calculate_fractal_and_write_bmp(x_fractal: f64, y_fractal: f64, bmp_file_
 
     files::write_binary_file(bmp_file_path, &bmp_bytes);
 }
+
+/// Writes a grayscale image to a binary PGM (P5) file.
+///
+/// # Arguments
+/// * `file_path` - Path to the output PGM file.
+/// * `width` - Width of the image in pixels.
+/// * `height` - Height of the image in pixels.
+/// * `intensities` - Pixel intensities (0 = black, 255 = white), row by row from the top.
+///
+/// # Panics
+/// Panics if `intensities` does not contain exactly `width * height` pixels.
+///
+/// # Example
+/// ```
+/// write_pgm(&String::from("image.pgm"), 2, 1, &[0, 255]);
+/// ```
+///
+/// # Reference
+/// [Netpbm - Wikipedia](https://en.wikipedia.org/wiki/Netpbm#File_formats)
+pub fn write_pgm(file_path: &String, width: u32, height: u32, intensities: &[u8]) {
+    assert_eq!(
+        intensities.len(),
+        (width as usize) * (height as usize),
+        "Error in write_pgm: the number of pixels must be width * height"
+    );
+
+    // Header: magic number, dimensions, and maximum gray value
+    let header: String = format!("P5\n{} {}\n255\n", width, height);
+    let mut pgm_bytes: Vec<u8> = header.into_bytes();
+    pgm_bytes.extend_from_slice(intensities);
+
+    files::write_binary_file(file_path, &pgm_bytes);
+}
+
+/// Calculates a fractal image and writes it to a grayscale PGM file.
+///
+/// # Arguments
+/// * `x_fractal`, `y_fractal` - Fractal parameters.
+/// * `pgm_file_path` - Path to the output PGM file.
+///
+/// # Example
+/// ```
+/// calculate_fractal_and_write_pgm(-0.7, 0.27015, &String::from("fractal.pgm"));
+/// ```
+pub fn calculate_fractal_and_write_pgm(x_fractal: f64, y_fractal: f64, pgm_file_path: &String) {
+    let params = FractalParams::new(x_fractal, y_fractal);
+    let width: u32 = params.width;
+    let height: u32 = params.height;
+
+    let img_pixels: Vec<f64> = render_fractal(params);
+
+    // Norms are already in [0, 1]
+    let intensities: Vec<u8> = img_pixels
+        .iter()
+        .map(|&pixel| (255. * pixel) as u8)
+        .collect();
+
+    write_pgm(pgm_file_path, width, height, &intensities);
+}
+
+/// Writes raw fractal values to a CSV file, for analysis in external tools.
+///
+/// Each image row becomes a CSV row of `width` comma-separated values. Values are written
+/// with the shortest representation that parses back to the same f64.
+///
+/// # Arguments
+/// * `file_path` - Path to the output CSV file.
+/// * `width` - Width of the image in pixels.
+/// * `height` - Height of the image in pixels.
+/// * `pixels` - Fractal values, row by row (as returned by `render_fractal`).
+///
+/// # Panics
+/// Panics if `pixels` does not contain exactly `width * height` values.
+///
+/// # Example
+/// ```
+/// let params = FractalParams::new(0.3, 0.5);
+/// let (width, height) = (params.width, params.height);
+/// let pixels = render_fractal(params);
+/// write_fractal_csv(&String::from("fractal.csv"), width, height, &pixels);
+/// ```
+pub fn write_fractal_csv(file_path: &String, width: u32, height: u32, pixels: &[f64]) {
+    assert_eq!(
+        pixels.len(),
+        (width as usize) * (height as usize),
+        "Error in write_fractal_csv: the number of pixels must be width * height"
+    );
+
+    let rows: Vec<Vec<String>> = pixels
+        .chunks(width.max(1) as usize)
+        .map(|row| row.iter().map(|value| value.to_string()).collect())
+        .collect();
+
+    files::write_text_file(file_path, &files::write_csv(&rows, ','));
+}
@@ -2,6 +2,8 @@
 //!
 //! Implementation of Dijkstra's algorithm for pathfinding in graphs and mazes.
 //! Includes a maze solver that finds the shortest path between start and end points.
+//! Weighted mazes (opt-in) may carry a terrain weight on cells (digits '1' to '9',
+//! the cost of entering the cell).
 //!
 //! Author: Vincent Espitalier
 //! Date: June 2024
@@ -35,11 +37,33 @@ where
     fn list_neighbors_and_distances(&self, vertex: &S) -> Vec<(S, A)>;
 }
 
+/// Trait for distance types having an additive identity (the distance from a vertex to itself).
+pub trait Zero {
+    /// Returns the zero value of the type.
+    fn zero() -> Self;
+}
+
+/// Implements `Zero` for numeric primitive types.
+macro_rules! impl_zero {
+    ($zero:expr, $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+        )*
+    };
+}
+
+impl_zero!(0, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_zero!(0., f32, f64);
+
 /// Finds the key with the minimum value in a HashMap.
 ///
 /// # Type Parameters
 /// * `S` - Key type (must be Eq, Hash, and Clone)
-/// * `A` - Value type (must be PartialOrd, Add, TryFrom<i8>, Clone, and Debug)
+/// * `A` - Value type (must be PartialOrd, Add, Clone, and Debug)
 ///
 /// # Arguments
 /// * `map` - The HashMap to search
@@ -49,7 +73,7 @@ where
 fn find_min_key_value_pair<S, A>(map: &HashMap<S, A>) -> (S, A)
 where
     S: Eq + Hash + Clone,
-    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug,
+    A: PartialOrd + Add + Clone + Debug,
 {
     let mut min_key: Option<&S> = None;
     let mut min_value: Option<&A> = None;
@@ -69,12 +93,52 @@ where
     (min_key.unwrap().clone(), min_value.unwrap().clone())
 }
 
+/// Result of a Dijkstra search (see `solve_dijkstra`).
+///
+/// # Type Parameters
+/// * `S` - Vertex type
+/// * `A` - Distance type
+#[derive(Clone, Debug)]
+pub struct DijkstraResult<S, A> {
+    /// Processed vertices and their shortest distances from the start vertices
+    pub distances: HashMap<S, A>,
+    /// Predecessor of each reached vertex on its shortest path
+    pub predecessors: HashMap<S, S>,
+    /// First end vertex reached (the nearest one), if any
+    pub reached_end: Option<S>,
+}
+
+impl<S, A> DijkstraResult<S, A>
+where
+    S: Eq + Hash + Clone,
+{
+    #[allow(dead_code)]
+    /// Returns the shortest distance from the start vertices to a processed vertex.
+    pub fn distance_to(&self, vertex: &S) -> Option<&A> {
+        self.distances.get(vertex)
+    }
+
+    #[allow(dead_code)]
+    /// Returns the shortest path from a start vertex to a processed vertex (both included).
+    ///
+    /// # Returns
+    /// The vertices along the path, or an empty vector if `vertex` was not processed
+    pub fn path_to(&self, vertex: &S) -> Vec<S> {
+        if !self.distances.contains_key(vertex) {
+            return Vec::new();
+        }
+        let mut path = follow_predecessors(&self.predecessors, vertex);
+        path.reverse();
+        path
+    }
+}
+
 /// Solves the shortest path problem using Dijkstra's algorithm.
 ///
 /// # Type Parameters
 /// * `G` - Graph type implementing Neighbors trait
 /// * `S` - Vertex type (must be Eq, Hash, and Clone)
-/// * `A` - Distance type (must be PartialOrd, Add, TryFrom<i8>, Clone, Debug, and Add with Output=A)
+/// * `A` - Distance type (must be PartialOrd, Zero, Clone, Debug, and Add with Output=A)
 ///
 /// # Arguments
 /// * `graph` - The graph to search
@@ -82,14 +146,17 @@ where
 /// * `end_vertices` - Vector of target vertices
 ///
 /// # Returns
-/// A tuple containing:
+/// A `DijkstraResult` containing:
 /// 1. HashMap of all visited vertices and their distances from start
 /// 2. HashMap of predecessors for path reconstruction
 /// 3. Option containing the first reached end vertex (if any)
 ///
 /// # Example
 /// ```
-/// let (distances, predecessors, end_vertex) = solve_dijkstra(&graph, start_vertices, end_vertices);
+/// let result = solve_dijkstra(&graph, start_vertices, end_vertices);
+/// if let Some(end_vertex) = &result.reached_end {
+///     let path = result.path_to(end_vertex);
+/// }
 /// ```
 ///
 /// # Reference
@@ -98,12 +165,11 @@ pub fn solve_dijkstra<G, S, A>(
     graph: &G,
     start_vertices: Vec<S>,
     end_vertices: Vec<S>,
-) -> (HashMap<S, A>, HashMap<S, S>, Option<S>)
+) -> DijkstraResult<S, A>
 where
     G: Neighbors<S, A>,
     S: Eq + Hash + Clone,
-    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
-    <A as TryFrom<i8>>::Error: Debug,
+    A: PartialOrd + Zero + Clone + Debug + Add<Output = A>,
 {
     // HashMap of processed vertices with their distances from start (initially empty)
     let mut processed: HashMap<S, A> = HashMap::new();
@@ -115,7 +181,7 @@ where
     let mut predecessors: HashMap<S, S> = HashMap::new();
 
     // Initialize distances for start vertices to 0
-    let zero_distance: A = A::try_from(0i8).expect("Missing zero distance for type A.");
+    let zero_distance: A = A::zero();
     for vertex in start_vertices.iter() {
         current.insert(vertex.clone(), zero_distance.clone());
     }
@@ -165,7 +231,455 @@ where
         }
     }
 
-    (processed, predecessors, end_vertex)
+    DijkstraResult {
+        distances: processed,
+        predecessors,
+        reached_end: end_vertex,
+    }
+}
+
+/// Follows a predecessor map from `vertex` back to the root of the search.
+///
+/// # Returns
+/// The vertices from `vertex` (included) to the root (included)
+fn follow_predecessors<S>(predecessors: &HashMap<S, S>, vertex: &S) -> Vec<S>
+where
+    S: Eq + Hash + Clone,
+{
+    let mut chain: Vec<S> = vec![vertex.clone()];
+    let mut current_vertex = vertex;
+    while let Some(predecessor) = predecessors.get(current_vertex) {
+        chain.push(predecessor.clone());
+        current_vertex = predecessor;
+    }
+    chain
+}
+
+#[allow(dead_code)]
+/// Finds the closest pair of start and end vertices, with a multi-source Dijkstra search.
+///
+/// All start vertices are initialized at distance zero, so the first end vertex reached
+/// is the nearest one from any start, and following its predecessors leads to the start
+/// vertex it was reached from.
+///
+/// # Type Parameters
+/// * `G` - Graph type implementing Neighbors trait
+/// * `S` - Vertex type (must be Eq, Hash, and Clone)
+/// * `A` - Distance type (same requirements as for `solve_dijkstra`)
+///
+/// # Arguments
+/// * `graph` - The graph to search
+/// * `start_vertices` - Vector of starting vertices
+/// * `end_vertices` - Vector of target vertices
+///
+/// # Returns
+/// The (start, end, distance) triple of the closest pair, or None if no end vertex can be reached
+///
+/// # Example
+/// ```
+/// let (start, end, distance) = solve_nearest_pair(&maze, maze.start_positions(), maze.end_positions()).unwrap();
+/// ```
+pub fn solve_nearest_pair<G, S, A>(
+    graph: &G,
+    start_vertices: Vec<S>,
+    end_vertices: Vec<S>,
+) -> Option<(S, S, A)>
+where
+    G: Neighbors<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Zero + Clone + Debug + Add<Output = A>,
+{
+    let result = solve_dijkstra(graph, start_vertices, end_vertices);
+    let end_vertex = result.reached_end.clone()?;
+
+    // The path starts at the start vertex the end was reached from
+    let start_vertex = result.path_to(&end_vertex).swap_remove(0);
+    let distance = result.distances[&end_vertex].clone();
+    Some((start_vertex, end_vertex, distance))
+}
+
+#[allow(dead_code)]
+/// Solves the single-pair shortest path problem with a bidirectional Dijkstra search.
+///
+/// Two searches are run alternately, one from `start` and one from `end`, and stop
+/// once the sum of their smallest tentative distances can no longer improve the best
+/// path found through a vertex reached by both. Each search explores roughly a disc
+/// of half the radius, hence fewer vertices than a single search.
+///
+/// The graph edges must be navigable in both directions with the same distance
+/// (the backward search uses `list_neighbors_and_distances` as well).
+///
+/// # Type Parameters
+/// * `G` - Graph type implementing Neighbors trait
+/// * `S` - Vertex type (must be Eq, Hash, and Clone)
+/// * `A` - Distance type (same requirements as for `solve_dijkstra`)
+///
+/// # Arguments
+/// * `graph` - The graph to search
+/// * `start` - Starting vertex
+/// * `end` - Target vertex
+///
+/// # Returns
+/// The shortest distance and the path from `start` to `end` (both included),
+/// or None if `end` cannot be reached
+///
+/// # Example
+/// ```
+/// let (distance, path) = solve_dijkstra_bidirectional(&graph, start, end).unwrap();
+/// ```
+///
+/// # Reference
+/// [Bidirectional search - Wikipedia](https://en.wikipedia.org/wiki/Bidirectional_search)
+pub fn solve_dijkstra_bidirectional<G, S, A>(graph: &G, start: S, end: S) -> Option<(A, Vec<S>)>
+where
+    G: Neighbors<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Zero + Clone + Debug + Add<Output = A>,
+{
+    let zero_distance: A = A::zero();
+    if start == end {
+        return Some((zero_distance, vec![start]));
+    }
+
+    // Index 0: forward search (from start), index 1: backward search (from end)
+    let mut processed: [HashMap<S, A>; 2] = [HashMap::new(), HashMap::new()];
+    let mut current: [HashMap<S, A>; 2] = [HashMap::new(), HashMap::new()];
+    let mut predecessors: [HashMap<S, S>; 2] = [HashMap::new(), HashMap::new()];
+    current[0].insert(start, zero_distance.clone());
+    current[1].insert(end, zero_distance);
+
+    // Best path found so far: total distance and meeting vertex
+    let mut best: Option<(A, S)> = None;
+
+    while !current[0].is_empty() && !current[1].is_empty() {
+        let (forward_vertex, forward_distance) = find_min_key_value_pair(&current[0]);
+        let (backward_vertex, backward_distance) = find_min_key_value_pair(&current[1]);
+
+        // Stopping criterion: no shorter path can be found anymore
+        if let Some((best_distance, _)) = &best {
+            if forward_distance.clone() + backward_distance.clone() >= *best_distance {
+                break;
+            }
+        }
+
+        // Expand the search with the smallest tentative distance
+        let (side, vertex, distance) = if forward_distance <= backward_distance {
+            (0, forward_vertex, forward_distance)
+        } else {
+            (1, backward_vertex, backward_distance)
+        };
+        let other_side = 1 - side;
+
+        current[side].remove(&vertex);
+        processed[side].insert(vertex.clone(), distance.clone());
+
+        let neighbors: Vec<(S, A)> = graph.list_neighbors_and_distances(&vertex);
+        for (neighbor, neighbor_distance) in neighbors.iter() {
+            if processed[side].contains_key(neighbor) {
+                continue;
+            }
+
+            let new_distance = distance.clone() + neighbor_distance.clone();
+            let is_better = match current[side].get(neighbor) {
+                Some(current_dist) => &new_distance < current_dist,
+                None => true,
+            };
+            if !is_better {
+                continue;
+            }
+            current[side].insert(neighbor.clone(), new_distance.clone());
+            predecessors[side].insert(neighbor.clone(), vertex.clone());
+
+            // Check if the neighbor connects both searches through a shorter path
+            let other_distance = processed[other_side]
+                .get(neighbor)
+                .or_else(|| current[other_side].get(neighbor));
+            if let Some(other_dist) = other_distance {
+                let total_distance = new_distance + other_dist.clone();
+                let is_best = match &best {
+                    Some((best_distance, _)) => &total_distance < best_distance,
+                    None => true,
+                };
+                if is_best {
+                    best = Some((total_distance, neighbor.clone()));
+                }
+            }
+        }
+    }
+
+    let (best_distance, meeting_vertex) = best?;
+
+    // Rebuild the path: start -> meeting vertex -> end
+    let mut path: Vec<S> = follow_predecessors(&predecessors[0], &meeting_vertex);
+    path.reverse();
+    let backward_path: Vec<S> = follow_predecessors(&predecessors[1], &meeting_vertex);
+    path.extend(backward_path.into_iter().skip(1));
+
+    Some((best_distance, path))
+}
+
+#[allow(dead_code)]
+/// Undirected weighted graph, stored as an edge list plus an adjacency map.
+///
+/// # Type Parameters
+/// * `S` - Vertex type
+/// * `A` - Edge weight type
+pub struct Graph<S, A> {
+    vertices: Vec<S>,
+    edges: Vec<(S, S, A)>,
+    adjacency: HashMap<S, Vec<(S, A)>>,
+}
+
+#[allow(dead_code)]
+impl<S, A> Graph<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: Clone,
+{
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Graph {
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            adjacency: HashMap::new(),
+        }
+    }
+
+    /// Adds a vertex to the graph (no effect if it already exists).
+    ///
+    /// # Arguments
+    /// * `vertex` - The vertex to add
+    pub fn add_vertex(&mut self, vertex: S) {
+        if !self.adjacency.contains_key(&vertex) {
+            self.adjacency.insert(vertex.clone(), Vec::new());
+            self.vertices.push(vertex);
+        }
+    }
+
+    /// Adds an undirected edge between two vertices, adding the vertices if needed.
+    ///
+    /// # Arguments
+    /// * `a`, `b` - The edge endpoints
+    /// * `weight` - The edge weight
+    pub fn add_edge(&mut self, a: S, b: S, weight: A) {
+        self.add_vertex(a.clone());
+        self.add_vertex(b.clone());
+        self.adjacency
+            .get_mut(&a)
+            .unwrap()
+            .push((b.clone(), weight.clone()));
+        self.adjacency
+            .get_mut(&b)
+            .unwrap()
+            .push((a.clone(), weight.clone()));
+        self.edges.push((a, b, weight));
+    }
+
+    /// Gets the vertices, in insertion order.
+    pub fn vertices(&self) -> &[S] {
+        &self.vertices
+    }
+
+    /// Gets the undirected edges as (endpoint, endpoint, weight), in insertion order.
+    pub fn edges(&self) -> &[(S, S, A)] {
+        &self.edges
+    }
+}
+
+impl<S, A> Default for Graph<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, A> Neighbors<S, A> for Graph<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + Clone,
+{
+    fn list_neighbors_and_distances(&self, vertex: &S) -> Vec<(S, A)> {
+        self.adjacency.get(vertex).cloned().unwrap_or_default()
+    }
+}
+
+#[allow(dead_code)]
+/// Disjoint-set (union-find) structure over the elements `0..n`.
+///
+/// Uses path compression and union by rank, so that operations run in
+/// near-constant amortized time.
+///
+/// # Reference
+/// [Disjoint-set data structure - Wikipedia](https://en.wikipedia.org/wiki/Disjoint-set_data_structure)
+///
+/// # Example
+/// ```
+/// let mut sets = UnionFind::new(4);
+/// sets.union(0, 1);
+/// assert!(sets.connected(0, 1));
+/// assert_eq!(sets.component_count(), 3);
+/// ```
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u32>,
+    component_count: usize,
+}
+
+#[allow(dead_code)]
+impl UnionFind {
+    /// Creates `n` singleton sets {0}, {1}, ..., {n - 1}.
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+            component_count: n,
+        }
+    }
+
+    /// Finds the representative of the set containing `x`.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Path compression: attach every node of the path directly to the root
+        let mut current = x;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// # Returns
+    /// `true` if the sets were merged, `false` if `a` and `b` were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return false;
+        }
+
+        // Union by rank: attach the shallower tree under the deeper one
+        match self.ranks[root_a].cmp(&self.ranks[root_b]) {
+            std::cmp::Ordering::Less => self.parents[root_a] = root_b,
+            std::cmp::Ordering::Greater => self.parents[root_b] = root_a,
+            std::cmp::Ordering::Equal => {
+                self.parents[root_b] = root_a;
+                self.ranks[root_a] += 1;
+            }
+        }
+        self.component_count -= 1;
+        true
+    }
+
+    /// Tests whether `a` and `b` belong to the same set.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Gets the current number of disjoint sets.
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+}
+
+#[allow(dead_code)]
+/// Computes a minimum spanning tree using Kruskal's algorithm.
+///
+/// Edges are considered by increasing weight, and kept whenever they join two
+/// different components (tracked with a `UnionFind`). If the graph is not connected,
+/// a minimum spanning forest is returned.
+///
+/// # Type Parameters
+/// * `S` - Vertex type (must be Eq, Hash, and Clone)
+/// * `A` - Edge weight type (must be Ord and Clone)
+///
+/// # Arguments
+/// * `graph` - The undirected graph
+///
+/// # Returns
+/// The edges of the minimum spanning tree, as (endpoint, endpoint, weight)
+///
+/// # Complexity
+/// O(E log E)
+///
+/// # Reference
+/// [Kruskal's algorithm - Wikipedia](https://en.wikipedia.org/wiki/Kruskal%27s_algorithm)
+pub fn minimum_spanning_tree<S, A>(graph: &Graph<S, A>) -> Vec<(S, S, A)>
+where
+    S: Eq + Hash + Clone,
+    A: Ord + Clone,
+{
+    // Map each vertex to an index for the union-find structure
+    let vertex_indices: HashMap<S, usize> = graph
+        .vertices()
+        .iter()
+        .enumerate()
+        .map(|(i, vertex)| (vertex.clone(), i))
+        .collect();
+
+    let mut sorted_edges: Vec<(S, S, A)> = graph.edges().to_vec();
+    sorted_edges.sort_by(|e1, e2| e1.2.cmp(&e2.2));
+
+    let mut components = UnionFind::new(vertex_indices.len());
+    let mut tree_edges: Vec<(S, S, A)> = Vec::new();
+    for (a, b, weight) in sorted_edges {
+        if components.union(vertex_indices[&a], vertex_indices[&b]) {
+            tree_edges.push((a, b, weight));
+        }
+    }
+
+    tree_edges
+}
+
+/// Default character marking start positions in a maze layout.
+const START_CHAR: char = '@';
+
+/// Default character marking end positions in a maze layout.
+const END_CHAR: char = '$';
+
+/// Cell coordinates in a maze layout.
+///
+/// Alternative to the u64 positions of `Maze` (height in the high 32 bits, width in the low 32 bits),
+/// both being usable as vertices for the solvers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Coord {
+    pub row: u32,
+    pub col: u32,
+}
+
+impl Coord {
+    /// Creates coordinates from a row (height) and a column (width).
+    pub fn new(row: u32, col: u32) -> Self {
+        Coord { row, col }
+    }
+}
+
+impl From<u64> for Coord {
+    /// Decodes a packed u64 maze position.
+    fn from(pos: u64) -> Self {
+        let (row, col) = Maze::position_to_coordinates(pos);
+        Coord { row, col }
+    }
+}
+
+impl From<Coord> for u64 {
+    /// Encodes coordinates as a packed u64 maze position.
+    fn from(coord: Coord) -> Self {
+        Maze::coordinates_to_position(coord.row, coord.col)
+    }
 }
 
 /// Represents a maze with start and end positions.
@@ -177,6 +691,7 @@ pub struct Maze {
     end_positions: Vec<u64>,
     start_char: char,
     end_char: char,
+    weighted: bool,
 }
 
 impl Maze {
@@ -242,6 +757,9 @@ impl Maze {
 
     /// Creates a new Maze from a layout.
     ///
+    /// Only spaces, start and end characters are passable; all other characters
+    /// (including digits) are walls.
+    ///
     /// # Arguments
     /// * `layout` - The maze layout as a vector of strings
     ///
@@ -251,6 +769,29 @@ impl Maze {
     /// # Panics
     /// Panics if the maze has no lines or if lines have inconsistent widths
     pub fn new(layout: &[String]) -> Self {
+        Self::from_layout(layout, false)
+    }
+
+    #[allow(dead_code)]
+    /// Creates a new weighted Maze from a layout.
+    ///
+    /// Same as `new`, except that digits '1' to '9' are passable terrain, whose value is
+    /// the cost of entering the cell (other passable cells cost 1).
+    ///
+    /// # Arguments
+    /// * `layout` - The maze layout as a vector of strings
+    ///
+    /// # Returns
+    /// A new weighted Maze instance
+    ///
+    /// # Panics
+    /// Panics if the maze has no lines or if lines have inconsistent widths
+    pub fn new_weighted(layout: &[String]) -> Self {
+        Self::from_layout(layout, true)
+    }
+
+    /// Creates a Maze from a layout, with or without terrain weights.
+    fn from_layout(layout: &[String], weighted: bool) -> Self {
         let maze_layout = layout.to_owned();
         let maze_height = maze_layout.len() as u32;
 
@@ -265,8 +806,8 @@ impl Maze {
             );
         }
 
-        let start_char = '@';
-        let end_char = '$';
+        let start_char = START_CHAR;
+        let end_char = END_CHAR;
         let start_positions =
             Self::find_character_in_layout(&maze_layout, maze_width, maze_height, start_char);
         let end_positions =
@@ -280,6 +821,7 @@ impl Maze {
             end_positions,
             start_char,
             end_char,
+            weighted,
         }
     }
 
@@ -299,6 +841,31 @@ impl Maze {
         self.end_positions.clone()
     }
 
+    #[allow(dead_code)]
+    /// Gets the start positions as coordinates.
+    ///
+    /// # Returns
+    /// A vector of start coordinates
+    pub fn start_coords(&self) -> Vec<Coord> {
+        self.start_positions
+            .iter()
+            .map(|&pos| Coord::from(pos))
+            .collect()
+    }
+
+    #[allow(dead_code)]
+    /// Gets the end positions as coordinates.
+    ///
+    /// # Returns
+    /// A vector of end coordinates
+    pub fn end_coords(&self) -> Vec<Coord> {
+        self.end_positions
+            .iter()
+            .map(|&pos| Coord::from(pos))
+            .collect()
+    }
+
+    #[allow(dead_code)]
     /// Gets the start character.
     ///
     /// # Returns
@@ -307,6 +874,7 @@ impl Maze {
         self.start_char
     }
 
+    #[allow(dead_code)]
     /// Gets the end character.
     ///
     /// # Returns
@@ -314,67 +882,290 @@ impl Maze {
     pub fn end_char(&self) -> char {
         self.end_char
     }
+
+    #[allow(dead_code)]
+    /// Tells whether digits are passable weighted terrain (see `new_weighted`).
+    pub fn is_weighted(&self) -> bool {
+        self.weighted
+    }
+
+    /// Gets the cost of entering a cell.
+    ///
+    /// Spaces, start and end cells cost 1. On weighted mazes, digits '1' to '9' cost
+    /// their value. Other characters are walls.
+    ///
+    /// # Arguments
+    /// * `coord` - The cell coordinates
+    ///
+    /// # Returns
+    /// The cost of the cell, or None for walls and out-of-bounds coordinates
+    pub fn cell_cost(&self, coord: Coord) -> Option<u64> {
+        if coord.row >= self.height || coord.col >= self.width {
+            return None;
+        }
+        let cell: char = self.layout[coord.row as usize]
+            .chars()
+            .nth(coord.col as usize)
+            .unwrap();
+        if cell == ' ' || cell == self.start_char || cell == self.end_char {
+            return Some(1);
+        }
+        if !self.weighted {
+            return None;
+        }
+        match cell.to_digit(10) {
+            Some(weight) if weight > 0 => Some(weight as u64),
+            _ => None,
+        }
+    }
+}
+
+impl Neighbors<Coord, u64> for Maze {
+    fn list_neighbors_and_distances(&self, coord: &Coord) -> Vec<(Coord, u64)> {
+        let mut neighbors: Vec<(Coord, u64)> = Vec::new();
+
+        // Possible neighbors are the 4 directions (up, down, left, right)
+        let mut possible_neighbors: Vec<Coord> = Vec::new();
+        if coord.row > 0 {
+            possible_neighbors.push(Coord::new(coord.row - 1, coord.col));
+        }
+        possible_neighbors.push(Coord::new(coord.row + 1, coord.col));
+        if coord.col > 0 {
+            possible_neighbors.push(Coord::new(coord.row, coord.col - 1));
+        }
+        possible_neighbors.push(Coord::new(coord.row, coord.col + 1));
+
+        // The distance to a neighbor is the cost of entering it (walls and out-of-bounds cells are skipped)
+        for neighbor in possible_neighbors {
+            if let Some(cost) = self.cell_cost(neighbor) {
+                neighbors.push((neighbor, cost));
+            }
+        }
+
+        neighbors
+    }
 }
 
 impl Neighbors<u64, u64> for Maze {
+    /// Same neighbors as with `Coord` vertices, on the packed u64 encoding.
     fn list_neighbors_and_distances(&self, pos: &u64) -> Vec<(u64, u64)> {
-        let mut neighbors: Vec<(u64, u64)> = Vec::new();
+        self.list_neighbors_and_distances(&Coord::from(*pos))
+            .into_iter()
+            .map(|(neighbor, distance)| (u64::from(neighbor), distance))
+            .collect()
+    }
+}
 
-        // Possible neighbors are the 4 directions (up, down, left, right) at distance 1
-        let (height, width): (u32, u32) = Self::position_to_coordinates(*pos);
-        let mut possible_neighbors: Vec<(u64, u64)> = Vec::new();
+/// Character marking the cells visited by the solver.
+const VISITED_CHAR: char = 'o';
 
-        if height > 0 {
-            possible_neighbors.push((Self::coordinates_to_position(height - 1, width), 1));
-        }
-        possible_neighbors.push((Self::coordinates_to_position(height + 1, width), 1));
-        if width > 0 {
-            possible_neighbors.push((Self::coordinates_to_position(height, width - 1), 1));
-        }
-        possible_neighbors.push((Self::coordinates_to_position(height, width + 1), 1));
+/// Character marking the cells of the solution path.
+const PATH_CHAR: char = 'x';
+
+/// Annotates a maze layout with the visited cells and the solution path.
+///
+/// # Arguments
+/// * `layout` - The maze layout as a vector of strings
+/// * `visited` - Visited cells as (width, height) coordinates, marked with 'o'
+/// * `path` - Solution path cells as (width, height) coordinates, marked with 'x'
+///
+/// # Returns
+/// The annotated layout, as plain strings (no ANSI escape codes)
+///
+/// # Example
+/// ```
+/// let layout = vec!["@  $".to_string()];
+/// assert_eq!(render_solution(&layout, &[], &[(1, 0), (2, 0)]), vec!["@xx$"]);
+/// ```
+pub fn render_solution(
+    layout: &[String],
+    visited: &[(u32, u32)],
+    path: &[(u32, u32)],
+) -> Vec<String> {
+    let mut solution_grid: Vec<Vec<char>> =
+        layout.iter().map(|line| line.chars().collect()).collect();
 
-        // Can pass through spaces or start/end characters (not walls)
-        let passable_chars: Vec<char> = vec![' ', self.start_char, self.end_char];
+    // The path is drawn last, so that it overrides visited cells
+    for &(width, height) in visited {
+        solution_grid[height as usize][width as usize] = VISITED_CHAR;
+    }
+    for &(width, height) in path {
+        solution_grid[height as usize][width as usize] = PATH_CHAR;
+    }
 
-        for (neighbor, distance) in possible_neighbors {
-            let (neighbor_height, neighbor_width): (u32, u32) =
-                Self::position_to_coordinates(neighbor);
+    solution_grid
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect()
+}
 
-            // Skip if out of bounds
-            if neighbor_height >= self.height {
-                continue;
-            }
-            if neighbor_width >= self.width {
-                continue;
-            }
+/// Adds ANSI color codes to an annotated maze layout, for terminal display.
+///
+/// Visited cells are shown in gray, the path in yellow, start positions in blue
+/// and end positions in green.
+///
+/// # Arguments
+/// * `lines` - Annotated layout, as returned by `render_solution`
+///
+/// # Returns
+/// The colored lines
+pub fn colorize(lines: &[String]) -> Vec<String> {
+    let visited_char_colored = "\x1b[90mo\x1b[0m";
+    let path_char_colored = "\x1b[93mx\x1b[0m";
+    let start_char_colored = "\x1b[94m@\x1b[0m";
+    let end_char_colored = "\x1b[92m$\x1b[0m";
+
+    let mut colored_lines: Vec<String> = Vec::new();
+    for line in lines {
+        let colored_line = line.replace(VISITED_CHAR, visited_char_colored);
+        let colored_line = colored_line.replace(PATH_CHAR, path_char_colored);
+        let colored_line = colored_line.replace(START_CHAR, start_char_colored);
+        let colored_line = colored_line.replace(END_CHAR, end_char_colored);
+        colored_lines.push(colored_line);
+    }
+    colored_lines
+}
 
-            let current_cell: char = self.layout[neighbor_height as usize]
-                .chars()
-                .collect::<Vec<_>>()[neighbor_width as usize];
-            if !passable_chars.contains(&current_cell) {
+/// Adds ANSI color codes to an annotated weighted maze layout, shading the path by cost.
+///
+/// Path cells are shown in yellow on cost 1, in dark yellow on costs 2 to 4, and in red
+/// on costs 5 to 9. Other cells are colored as with `colorize`.
+///
+/// # Arguments
+/// * `lines` - Annotated layout, as returned by `render_solution`
+/// * `maze` - The maze, giving the cost of each path cell
+///
+/// # Returns
+/// The colored lines
+pub fn colorize_by_cost(lines: &[String], maze: &Maze) -> Vec<String> {
+    let mut colored_lines: Vec<String> = Vec::new();
+    for (row, line) in lines.iter().enumerate() {
+        let mut colored_line = String::new();
+        for (col, current_char) in line.chars().enumerate() {
+            if current_char != PATH_CHAR {
+                colored_line.push_str(&colorize(&[current_char.to_string()])[0]);
                 continue;
             }
-            neighbors.push((neighbor, distance));
+            let color_code = match maze.cell_cost(Coord::new(row as u32, col as u32)) {
+                Some(5..) => 91,
+                Some(2..=4) => 33,
+                _ => 93,
+            };
+            colored_line.push_str(&format!("\x1b[{}m{}\x1b[0m", color_code, PATH_CHAR));
         }
+        colored_lines.push(colored_line);
+    }
+    colored_lines
+}
 
-        neighbors
+/// Tells whether a path crosses weighted terrain, i.e. a cell costing more than 1.
+///
+/// # Arguments
+/// * `maze` - The maze, giving the cost of each cell
+/// * `path` - Path cells as (width, height) coordinates
+///
+/// # Returns
+/// `true` if some path cell costs more than 1 (only possible on weighted mazes)
+pub fn crosses_weighted_terrain(maze: &Maze, path: &[(u32, u32)]) -> bool {
+    path.iter()
+        .any(|&(width, height)| maze.cell_cost(Coord::new(height, width)).unwrap_or(1) > 1)
+}
+
+/// Prepares an annotated maze layout for console display.
+///
+/// # Arguments
+/// * `lines` - Annotated layout, as returned by `render_solution`
+/// * `maze` - The maze, giving the cost of each path cell
+/// * `weighted_terrain` - Whether to shade the path by cost (see `colorize_by_cost`)
+/// * `use_color` - Whether to add ANSI color codes (plain text otherwise, e.g. when piping to a file)
+///
+/// # Returns
+/// The lines to display
+pub fn format_solution_display(
+    lines: &[String],
+    maze: &Maze,
+    weighted_terrain: bool,
+    use_color: bool,
+) -> Vec<String> {
+    if !use_color {
+        lines.to_vec()
+    } else if weighted_terrain {
+        colorize_by_cost(lines, maze)
+    } else {
+        colorize(lines)
     }
 }
 
+/// Metrics of a maze resolution, to compare pathfinding strategies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MazeStats {
+    /// Total cost of the shortest path from a start to an end position
+    /// (equal to the number of steps on unweighted mazes; 0 if unsolved)
+    pub path_length: u64,
+    /// Number of steps (moves between adjacent cells) along that path (0 if unsolved)
+    pub steps: u64,
+    /// Number of vertices processed by the solver
+    pub nodes_expanded: usize,
+    /// Whether an end position was reached
+    pub solved: bool,
+}
+
 /// Solves a maze and saves the solution to a file.
 ///
+/// The solution file is only written if the maze is solved.
+/// Digits in the layout are walls; see `solve_weighted_maze` for weighted terrain.
+///
 /// # Arguments
 /// * `maze_file` - Path to the maze input file
 /// * `solution_file` - Path to save the solution
+/// * `use_color` - Whether the console output uses ANSI color codes (the solution file is always plain)
+///
+/// # Returns
+/// The resolution metrics (path cost, number of steps, number of expanded nodes)
 ///
 /// # Example
 /// ```
-/// solve_maze("maze.txt".to_string(), "solution.txt".to_string());
+/// let stats = solve_maze("maze.txt".to_string(), "solution.txt".to_string(), true);
+/// println!("Path length: {}", stats.path_length);
 /// ```
-pub fn solve_maze(maze_file: String, solution_file: String) {
+pub fn solve_maze(maze_file: String, solution_file: String, use_color: bool) -> MazeStats {
     let maze_layout: Vec<String> = files::read_text_file_lines(&maze_file, None);
     let maze: Maze = Maze::new(&maze_layout);
+    solve_loaded_maze(&maze, &maze_layout, &solution_file, use_color)
+}
 
+#[allow(dead_code)]
+/// Solves a weighted maze (see `Maze::new_weighted`) and saves the solution to a file.
+///
+/// Same as `solve_maze`, except that digits '1' to '9' are passable terrain costing their
+/// value. When the path crosses such terrain, the total path cost is printed and the
+/// displayed path is shaded by cost.
+///
+/// # Arguments
+/// * `maze_file` - Path to the maze input file
+/// * `solution_file` - Path to save the solution
+/// * `use_color` - Whether the console output uses ANSI color codes (the solution file is always plain)
+///
+/// # Returns
+/// The resolution metrics (path cost, number of steps, number of expanded nodes)
+///
+/// # Example
+/// ```
+/// let stats = solve_weighted_maze("maze.txt".to_string(), "solution.txt".to_string(), true);
+/// ```
+pub fn solve_weighted_maze(maze_file: String, solution_file: String, use_color: bool) -> MazeStats {
+    let maze_layout: Vec<String> = files::read_text_file_lines(&maze_file, None);
+    let maze: Maze = Maze::new_weighted(&maze_layout);
+    solve_loaded_maze(&maze, &maze_layout, &solution_file, use_color)
+}
+
+/// Solves a maze built from `maze_layout`, displays the solution and saves it to a file.
+fn solve_loaded_maze(
+    maze: &Maze,
+    maze_layout: &[String],
+    solution_file: &String,
+    use_color: bool,
+) -> MazeStats {
     let start_positions: Vec<u64> = maze.start_positions();
     let end_positions: Vec<u64> = maze.end_positions();
 
@@ -390,8 +1181,11 @@ pub fn solve_maze(maze_file: String, solution_file: String) {
         println!("(x,y) = ({},{})", width, height);
     }
 
-    let (distances, predecessors, end_vertex) =
-        solve_dijkstra(&maze, start_positions, end_positions);
+    let DijkstraResult {
+        distances,
+        predecessors,
+        reached_end: end_vertex,
+    } = solve_dijkstra(maze, start_positions, end_positions);
 
     if let Some(final_vertex) = end_vertex {
         let final_distance = distances[&final_vertex];
@@ -400,6 +1194,11 @@ pub fn solve_maze(maze_file: String, solution_file: String) {
             "End vertex ({}, {}) has a distance of: {}",
             width, height, final_distance
         );
+        let nearest_start = *follow_predecessors(&predecessors, &final_vertex)
+            .last()
+            .unwrap();
+        let (start_height, start_width) = Maze::position_to_coordinates(nearest_start);
+        println!("Nearest start vertex: ({}, {})", start_width, start_height);
 
         // Store all visited vertices
         let mut visited_vertices: Vec<(u32, u32)> = Vec::new();
@@ -428,44 +1227,40 @@ pub fn solve_maze(maze_file: String, solution_file: String) {
             solution_path.push((width, height));
         }
 
-        // Save solution to file
-        let mut solution_layout = maze_layout.clone();
-
-        let visited_char = 'o';
-        for (width, height) in visited_vertices {
-            let mut current_line: Vec<char> = solution_layout[height as usize].chars().collect();
-            current_line[width as usize] = visited_char;
-            let line_string = current_line.iter().collect::<String>();
-            solution_layout[height as usize] = line_string;
-        }
+        // Each path cell but the start is entered once: the intermediate cells, then the end
+        let steps = solution_path.len() as u64 + 1;
 
-        let path_char = 'x';
-        for (width, height) in solution_path {
-            let mut current_line: Vec<char> = solution_layout[height as usize].chars().collect();
-            current_line[width as usize] = path_char;
-            let line_string = current_line.iter().collect::<String>();
-            solution_layout[height as usize] = line_string;
-        }
+        // Save solution to file
+        let solution_layout = render_solution(maze_layout, &visited_vertices, &solution_path);
+        files::write_text_file_lines(solution_file, &solution_layout);
 
-        files::write_text_file_lines(&solution_file, &solution_layout);
-
-        // Create colored output for display
-        let visited_char_colored = "\x1b[90mo\x1b[0m";
-        let path_char_colored = "\x1b[93mx\x1b[0m";
-        let start_char_colored = "\x1b[94m@\x1b[0m";
-        let end_char_colored = "\x1b[92m$\x1b[0m";
-
-        let mut colored_solution: Vec<String> = Vec::new();
-        for line in solution_layout {
-            let colored_line = line.replace(visited_char, visited_char_colored);
-            let colored_line = colored_line.replace(path_char, path_char_colored);
-            let colored_line = colored_line.replace(maze.start_char(), start_char_colored);
-            let colored_line = colored_line.replace(maze.end_char(), end_char_colored);
-            colored_solution.push(colored_line);
+        // When the path crosses weighted terrain, report the total cost and shade the path by cost
+        let weighted_terrain = crosses_weighted_terrain(maze, &solution_path);
+        if weighted_terrain {
+            println!(
+                "Weighted terrain: total path cost of {} over {} steps",
+                final_distance, steps
+            );
         }
 
-        // Display colored solution
+        // Display the solution
+        let displayed_solution =
+            format_solution_display(&solution_layout, maze, weighted_terrain, use_color);
         println!("Solution (via Dijkstra's algorithm)");
-        println!("{}", colored_solution.join("\n"));
+        println!("{}", displayed_solution.join("\n"));
+
+        MazeStats {
+            path_length: final_distance,
+            steps,
+            nodes_expanded: distances.len(),
+            solved: true,
+        }
+    } else {
+        MazeStats {
+            path_length: 0,
+            steps: 0,
+            nodes_expanded: distances.len(),
+            solved: false,
+        }
     }
 }
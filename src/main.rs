@@ -10,16 +10,20 @@
 //! - Integer conversions
 //! - Graph/maze solving
 //! - Fractal generation
+//! - Numerical methods
+//! - Data structures
 //!
 //! Author: Vincent Espitalier
 //! Date: June 2024
 
 mod classics;
 mod conversions_hexa_bin_dec;
+mod data_structures;
 mod files;
 mod fractals;
 mod graphs_mazes;
 mod misc;
+mod numerical_methods;
 mod probabilities;
 mod rational;
 mod sorting;
@@ -400,7 +404,7 @@ fn main() {
 
             let solution_file =
                 maze_file.split('.').collect::<Vec<_>>()[0].to_string() + "_solution.txt";
-            graphs_mazes::solve_maze(maze_file, solution_file);
+            graphs_mazes::solve_maze(maze_file, solution_file, true);
         }
     }
 
@@ -6,7 +6,11 @@
 //! Author: Vincent Espitalier
 //! Date: June 2024
 
+#![allow(dead_code)]
+
+use crate::classics::gcd;
 use std::arch::asm;
+use std::ops::{Add, Mul, Sub};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -309,7 +313,8 @@ pub fn find_primes(min_n: usize, max_n: usize) -> Vec<usize> {
         min_n + (1 - min_n % 2)
     };
     for i in (min_odd_n..max_n).step_by(2) {
-        let max_j = (i as f64).sqrt() as usize;
+        // Exact integer square root: a float square root may round below the root of p²
+        let max_j = isqrt(i as u64) as usize;
         let mut is_prime = true;
         for j in (3..=max_j).step_by(2) {
             if i % j == 0 {
@@ -382,6 +387,426 @@ pub fn find_primes_multithreaded(min_n: usize, max_n: usize, batch_size: usize)
     return shared_primes_found.lock().unwrap().clone();
 }
 
+/// Computes `base^exponent mod modulus` by binary exponentiation.
+///
+/// Intermediate products are computed on u128, so any u64 modulus is supported.
+///
+/// # Arguments
+/// * `base` - The base.
+/// * `exponent` - The exponent.
+/// * `modulus` - The modulus (must be non-zero).
+///
+/// # Returns
+/// `base^exponent mod modulus`.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(mod_pow(3, 4, 7), 4); // 81 = 11 * 7 + 4
+/// ```
+///
+/// # Complexity
+/// O(log exponent)
+///
+/// # See also
+/// [Modular exponentiation - Wikipedia](https://en.wikipedia.org/wiki/Modular_exponentiation)
+pub fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    assert_ne!(modulus, 0, "Error in mod_pow: modulus must be non-zero.");
+
+    let m = modulus as u128;
+    let mut result: u128 = 1 % m;
+    let mut current_base: u128 = (base as u128) % m;
+    let mut current_exponent = exponent;
+
+    while current_exponent > 0 {
+        if current_exponent & 1 == 1 {
+            result = (result * current_base) % m;
+        }
+        current_base = (current_base * current_base) % m;
+        current_exponent >>= 1;
+    }
+
+    result as u64
+}
+
+/// Integer modulo a given modulus, with wraparound arithmetic.
+///
+/// The value is always kept in [0, modulus). Products are computed with `u128`
+/// intermediates, so that no operation overflows for any `u64` modulus.
+///
+/// # Example
+/// ```rust
+/// let a = ModInt::new(7, 10);
+/// let b = ModInt::new(8, 10);
+/// assert_eq!((a * b).value(), 6);
+/// assert_eq!((a - b).value(), 9);
+/// ```
+///
+/// # See also
+/// [Modular arithmetic - Wikipedia](https://en.wikipedia.org/wiki/Modular_arithmetic)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+    /// Creates `value mod modulus`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert_ne!(
+            modulus, 0,
+            "Error in ModInt::new: modulus must be non-zero."
+        );
+        ModInt {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// Gets the value, in [0, modulus).
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Gets the modulus.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Computes `self^exponent`, by binary exponentiation (see `mod_pow`).
+    ///
+    /// # Complexity
+    /// O(log exponent)
+    pub fn pow(&self, exponent: u64) -> ModInt {
+        ModInt::new(mod_pow(self.value, exponent, self.modulus), self.modulus)
+    }
+
+    /// Checks that both operands share the same modulus.
+    fn assert_same_modulus(&self, other: &ModInt) {
+        assert_eq!(
+            self.modulus, other.modulus,
+            "Error in ModInt: operands must have the same modulus."
+        );
+    }
+}
+
+// Trait Add: c = a + b (mod m)
+impl Add for ModInt {
+    type Output = ModInt;
+
+    fn add(self, other: ModInt) -> ModInt {
+        self.assert_same_modulus(&other);
+        let sum = (self.value as u128 + other.value as u128) % (self.modulus as u128);
+        ModInt {
+            value: sum as u64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+// Trait Sub: c = a - b (mod m)
+impl Sub for ModInt {
+    type Output = ModInt;
+
+    fn sub(self, other: ModInt) -> ModInt {
+        self.assert_same_modulus(&other);
+        // Adding the modulus first keeps the difference non-negative
+        let difference = (self.value as u128 + self.modulus as u128 - other.value as u128)
+            % (self.modulus as u128);
+        ModInt {
+            value: difference as u64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+// Trait Mul: c = a * b (mod m)
+impl Mul for ModInt {
+    type Output = ModInt;
+
+    fn mul(self, other: ModInt) -> ModInt {
+        self.assert_same_modulus(&other);
+        let product = (self.value as u128 * other.value as u128) % (self.modulus as u128);
+        ModInt {
+            value: product as u64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+/// Computes the floor of the base-2 logarithm of `n`.
+///
+/// # Arguments
+/// * `n` - A positive integer.
+///
+/// # Returns
+/// The position of the highest set bit of `n`, i.e. the largest `k` with `2^k <= n`.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(ilog2(1), 0);
+/// assert_eq!(ilog2(1023), 9);
+/// assert_eq!(ilog2(1024), 10);
+/// ```
+///
+/// # Complexity
+/// O(1)
+pub fn ilog2(n: u64) -> u32 {
+    assert_ne!(n, 0, "Error in ilog2: the logarithm of zero is undefined.");
+    63 - n.leading_zeros()
+}
+
+/// Computes the exact integer square root of `n`, i.e. the largest `r` with `r * r <= n`.
+///
+/// Uses Newton's iteration on integers, `x <- (x + n / x) / 2`, starting from a power of
+/// two above the root: the iterates decrease towards the root and the first one that does
+/// not decrease is the answer. No floating point is involved, so the result is exact
+/// for all `u64` values (unlike `(n as f64).sqrt() as u64`, whose rounding is off by one
+/// for some large `n`).
+///
+/// # Arguments
+/// * `n` - The integer whose square root to compute.
+///
+/// # Returns
+/// `floor(sqrt(n))`.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(isqrt(15), 3);
+/// assert_eq!(isqrt(16), 4);
+/// ```
+///
+/// # Complexity
+/// O(log log n) iterations once close to the root, O(log n) in the worst case
+///
+/// # See also
+/// [Integer square root - Wikipedia](https://en.wikipedia.org/wiki/Integer_square_root)
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // 2^(floor(log2(n)) / 2 + 1) is greater than sqrt(n)
+    let mut x: u64 = 1 << (ilog2(n) / 2 + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// Sieve of Eratosthenes returning all primes strictly below `max_n`.
+///
+/// Used internally to compute the base primes of the segmented sieve.
+fn sieve_of_eratosthenes(max_n: usize) -> Vec<usize> {
+    if max_n <= 2 {
+        return Vec::new();
+    }
+
+    let mut is_prime: Vec<bool> = vec![true; max_n];
+    is_prime[0] = false;
+    is_prime[1] = false;
+
+    let mut i: usize = 2;
+    while i * i < max_n {
+        if is_prime[i] {
+            for multiple in (i * i..max_n).step_by(i) {
+                is_prime[multiple] = false;
+            }
+        }
+        i += 1;
+    }
+
+    is_prime
+        .iter()
+        .enumerate()
+        .filter(|(_, &prime)| prime)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Returns the nth prime number (1-indexed: the first prime is 2).
+///
+/// The sieve bound is estimated with `n * (ln n + ln ln n)`, an upper bound of the
+/// nth prime for n >= 6. Should the estimate ever be too small, the bound is doubled
+/// and the sieve is run again.
+///
+/// # Arguments
+/// * `n` - Rank of the prime (must be at least 1).
+///
+/// # Returns
+/// The nth prime number.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(nth_prime(10001), 104743);
+/// ```
+///
+/// # See also
+/// [Prime-counting function - Wikipedia](https://en.wikipedia.org/wiki/Prime-counting_function#Inequalities)
+pub fn nth_prime(n: usize) -> u64 {
+    assert_ne!(
+        n, 0,
+        "Error in nth_prime: n must be at least 1 (1-indexed)."
+    );
+
+    let mut max_n: usize = if n < 6 {
+        // The estimate is not an upper bound for small n (11 is the 5th prime)
+        12
+    } else {
+        let n_f64 = n as f64;
+        (n_f64 * (n_f64.ln() + n_f64.ln().ln())).ceil() as usize + 1
+    };
+
+    loop {
+        let primes: Vec<usize> = sieve_of_eratosthenes(max_n);
+        if primes.len() >= n {
+            return primes[n - 1] as u64;
+        }
+        max_n *= 2;
+    }
+}
+
+/// Finds primes between `min_n` (inclusive) and `max_n` (exclusive) with a segmented sieve.
+///
+/// The base primes up to √max_n are sieved first, then the interval [min_n, max_n) is
+/// sieved by segments of `segment_size` numbers, so memory stays bounded by
+/// O(√max_n + segment_size) regardless of `max_n`.
+///
+/// # Arguments
+/// * `min_n`, `max_n` - Range bounds.
+/// * `segment_size` - Number of integers sieved at once (must be non-zero).
+///
+/// # Returns
+/// Vector of primes in [min_n, max_n), in ascending order.
+///
+/// # Panics
+/// Panics if `segment_size` is zero.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(find_primes_segmented(10, 20, 4), vec![11, 13, 17, 19]);
+/// ```
+///
+/// # Complexity
+/// O(n log log n) for range [min_n, max_n).
+///
+/// # See also
+/// [Sieve of Eratosthenes - Wikipedia](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes#Segmented_sieve)
+pub fn find_primes_segmented(min_n: usize, max_n: usize, segment_size: usize) -> Vec<usize> {
+    if segment_size == 0 {
+        panic!("Error in find_primes_segmented: segment_size must be non-zero");
+    }
+
+    let mut primes_found: Vec<usize> = Vec::new();
+
+    // Base primes: all primes p such that p * p < max_n
+    let mut sqrt_max_n: usize = 1;
+    while sqrt_max_n * sqrt_max_n < max_n {
+        sqrt_max_n += 1;
+    }
+    let base_primes: Vec<usize> = sieve_of_eratosthenes(sqrt_max_n + 1);
+
+    let mut segment_min: usize = if min_n < 2 { 2 } else { min_n };
+    while segment_min < max_n {
+        let segment_max: usize = std::cmp::min(segment_min + segment_size, max_n);
+        let mut is_prime: Vec<bool> = vec![true; segment_max - segment_min];
+
+        for &p in base_primes.iter() {
+            if p * p >= segment_max {
+                break;
+            }
+            // First multiple of p in the segment, never below p * p
+            let first_multiple = segment_min.div_ceil(p) * p;
+            let start = std::cmp::max(p * p, first_multiple);
+            for multiple in (start..segment_max).step_by(p) {
+                is_prime[multiple - segment_min] = false;
+            }
+        }
+
+        for (i, &prime) in is_prime.iter().enumerate() {
+            if prime {
+                primes_found.push(segment_min + i);
+            }
+        }
+
+        segment_min = segment_max;
+    }
+
+    primes_found
+}
+
+/// Iterator over the prime numbers: 2, 3, 5, 7, 11, ...
+///
+/// Primes are found lazily, by trial division of odd candidates by the primes
+/// already found (up to the square root of the candidate), so no upper bound
+/// is needed up front.
+///
+/// # Example
+/// ```rust
+/// let primes: Vec<u64> = PrimeIter::new().take_while(|&p| p < 20).collect();
+/// assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+pub struct PrimeIter {
+    primes_found: Vec<u64>,
+}
+
+impl PrimeIter {
+    /// Creates an iterator starting at 2.
+    pub fn new() -> Self {
+        PrimeIter {
+            primes_found: Vec::new(),
+        }
+    }
+}
+
+impl Default for PrimeIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for PrimeIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let next_prime: u64 = match self.primes_found.last() {
+            None => 2,
+            Some(2) => 3,
+            Some(&last_prime) => {
+                let mut candidate = last_prime + 2;
+                loop {
+                    // Skip 2: candidates are odd
+                    let is_prime = self.primes_found[1..]
+                        .iter()
+                        .take_while(|&&p| p * p <= candidate)
+                        .all(|&p| !candidate.is_multiple_of(p));
+                    if is_prime {
+                        break candidate;
+                    }
+                    candidate += 2;
+                }
+            }
+        };
+
+        self.primes_found.push(next_prime);
+        Some(next_prime)
+    }
+}
+
 /// Calculates the flight time and maximum altitude for a Collatz sequence starting at `n`.
 pub fn calculate_collatz_flight_time_and_max_altitude(n: u64) -> (u64, u64) {
     let mut flight_time = 0;
@@ -416,3 +841,1450 @@ pub fn find_max_collatz_flight_time(n_max: u64) -> (u64, u64) {
     }
     (max_flight_time, max_flight_time_index)
 }
+
+/// Computes the sum of the proper divisors of `n` (all divisors except `n` itself).
+///
+/// Divisors are enumerated in pairs `(i, n / i)` for `i` up to √n.
+///
+/// # Arguments
+/// * `n` - A non-negative integer.
+///
+/// # Returns
+/// The sum of the proper divisors of `n` (0 for `n` = 0 or 1).
+///
+/// # Complexity
+/// O(√n)
+///
+/// # Example
+/// ```
+/// assert_eq!(sum_of_proper_divisors(28), 28);
+/// ```
+pub fn sum_of_proper_divisors(n: u64) -> u64 {
+    if n <= 1 {
+        return 0;
+    }
+
+    // 1 is always a proper divisor of n > 1
+    let mut sum: u64 = 1;
+    let mut i: u64 = 2;
+    while i * i <= n {
+        if n.is_multiple_of(i) {
+            let paired_divisor = n / i;
+            sum += i;
+            // Do not count the square root twice
+            if paired_divisor != i {
+                sum += paired_divisor;
+            }
+        }
+        i += 1;
+    }
+    sum
+}
+
+/// Classification of an integer according to the sum of its proper divisors.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NumberClass {
+    Deficient,
+    Perfect,
+    Abundant,
+}
+
+/// Classifies `n` as deficient, perfect or abundant.
+///
+/// # Arguments
+/// * `n` - A non-negative integer.
+///
+/// # Returns
+/// `Perfect` if the sum of proper divisors equals `n`, `Abundant` if it is greater,
+/// `Deficient` otherwise.
+///
+/// # Example
+/// ```
+/// assert_eq!(classify_number(28), NumberClass::Perfect);
+/// ```
+///
+/// # See also
+/// [Perfect number - Wikipedia](https://en.wikipedia.org/wiki/Perfect_number)
+pub fn classify_number(n: u64) -> NumberClass {
+    let sum = sum_of_proper_divisors(n);
+    match sum.cmp(&n) {
+        std::cmp::Ordering::Less => NumberClass::Deficient,
+        std::cmp::Ordering::Equal => NumberClass::Perfect,
+        std::cmp::Ordering::Greater => NumberClass::Abundant,
+    }
+}
+
+/// Decomposes `n` into prime factors, by trial division.
+///
+/// # Arguments
+/// * `n` - A non-negative integer.
+///
+/// # Returns
+/// The prime factors of `n` with their exponents, as (prime, exponent) by increasing
+/// prime. Empty for `n` = 0 or 1.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(prime_factorization(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// ```
+///
+/// # Complexity
+/// O(√n)
+///
+/// # See also
+/// [Integer factorization - Wikipedia](https://en.wikipedia.org/wiki/Integer_factorization)
+pub fn prime_factorization(n: u64) -> Vec<(u64, u32)> {
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    if n <= 1 {
+        return factors;
+    }
+
+    let mut remaining = n;
+    let mut divisor: u64 = 2;
+    // Division instead of divisor * divisor avoids overflows near u64::MAX
+    while divisor <= remaining / divisor {
+        let mut exponent: u32 = 0;
+        while remaining.is_multiple_of(divisor) {
+            remaining /= divisor;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((divisor, exponent));
+        }
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+
+    // What remains has no divisor below its square root: it is prime
+    if remaining > 1 {
+        factors.push((remaining, 1));
+    }
+    factors
+}
+
+/// Computes Euler's totient function φ(n), the count of integers in [1, n] coprime with `n`.
+///
+/// Uses the product formula over the distinct prime factors: `φ(n) = n * Π (1 - 1/p)`,
+/// computed exactly as `n / p * (p - 1)` for each factor.
+///
+/// # Arguments
+/// * `n` - A non-negative integer.
+///
+/// # Returns
+/// φ(n) (with φ(0) = 0 and φ(1) = 1).
+///
+/// # Example
+/// ```rust
+/// assert_eq!(euler_totient(36), 12);
+/// ```
+///
+/// # Complexity
+/// O(√n), for the factorization
+///
+/// # See also
+/// [Euler's totient function - Wikipedia](https://en.wikipedia.org/wiki/Euler%27s_totient_function)
+pub fn euler_totient(n: u64) -> u64 {
+    prime_factorization(n)
+        .iter()
+        .fold(n, |totient, &(prime, _)| totient / prime * (prime - 1))
+}
+
+/// Computes Euler's totient function for all integers up to `max_n`, with a sieve.
+///
+/// Starting from φ(i) = i, each prime `p` (detected as still having φ(p) = p) multiplies
+/// φ of all its multiples by (1 - 1/p).
+///
+/// # Arguments
+/// * `max_n` - The largest integer to compute φ for.
+///
+/// # Returns
+/// A vector of length `max_n + 1`, where the element at index `i` is φ(i).
+///
+/// # Example
+/// ```rust
+/// assert_eq!(euler_totient_sieve(6), vec![0, 1, 1, 2, 2, 4, 2]);
+/// ```
+///
+/// # Complexity
+/// O(n log log n)
+pub fn euler_totient_sieve(max_n: usize) -> Vec<u64> {
+    let mut totients: Vec<u64> = (0..=max_n as u64).collect();
+    for i in 2..=max_n {
+        // Untouched so far: i is prime
+        if totients[i] == i as u64 {
+            for multiple in (i..=max_n).step_by(i) {
+                totients[multiple] -= totients[multiple] / (i as u64);
+            }
+        }
+    }
+    totients
+}
+
+/// Solves the 0/1 knapsack problem by dynamic programming.
+///
+/// `table[i][c]` holds the best value achievable with the first `i` items and a capacity `c`.
+/// The chosen items are then recovered by walking the table backwards.
+///
+/// # Arguments
+/// * `weights` - Weight of each item.
+/// * `values` - Value of each item.
+/// * `capacity` - Maximum total weight.
+///
+/// # Returns
+/// A tuple containing the maximum achievable value and the indices (ascending) of the chosen items.
+///
+/// # Panics
+/// Panics if `weights` and `values` have different lengths.
+///
+/// # Complexity
+/// O(n * capacity) time and memory.
+///
+/// # Example
+/// ```
+/// let (value, items) = knapsack_01(&[1, 3, 4, 5], &[1, 4, 5, 7], 7);
+/// assert_eq!(value, 9);
+/// assert_eq!(items, vec![1, 2]);
+/// ```
+///
+/// # See also
+/// [Knapsack problem - Wikipedia](https://en.wikipedia.org/wiki/Knapsack_problem#0-1_knapsack_problem)
+pub fn knapsack_01(weights: &[u64], values: &[u64], capacity: u64) -> (u64, Vec<usize>) {
+    assert_eq!(
+        weights.len(),
+        values.len(),
+        "Error in knapsack_01: weights and values must have the same length"
+    );
+
+    let n = weights.len();
+    let capacity = capacity as usize;
+    let mut table: Vec<Vec<u64>> = vec![vec![0; capacity + 1]; n + 1];
+
+    for i in 1..=n {
+        let weight = weights[i - 1] as usize;
+        let value = values[i - 1];
+        for c in 0..=capacity {
+            // Best value without item i - 1
+            table[i][c] = table[i - 1][c];
+            // Best value with item i - 1, if it fits
+            if weight <= c && table[i - 1][c - weight] + value > table[i][c] {
+                table[i][c] = table[i - 1][c - weight] + value;
+            }
+        }
+    }
+
+    // Backtrack: an item was taken whenever the value changed with respect to the previous row
+    let mut chosen_items: Vec<usize> = Vec::new();
+    let mut c = capacity;
+    for i in (1..=n).rev() {
+        if table[i][c] != table[i - 1][c] {
+            chosen_items.push(i - 1);
+            c -= weights[i - 1] as usize;
+        }
+    }
+    chosen_items.reverse();
+
+    (table[n][capacity], chosen_items)
+}
+
+/// Computes a longest common subsequence of two slices.
+///
+/// Builds the classic dynamic programming table of LCS lengths, then backtracks
+/// from the bottom-right corner to recover one of the longest subsequences.
+///
+/// # Arguments
+/// * `a` - First sequence.
+/// * `b` - Second sequence.
+///
+/// # Returns
+/// A longest subsequence common to `a` and `b` (empty if there is none).
+///
+/// # Complexity
+/// O(n * m) time and memory.
+///
+/// # Example
+/// ```
+/// let lcs = longest_common_subsequence(&['A', 'B', 'C'], &['B', 'C', 'D']);
+/// assert_eq!(lcs, vec!['B', 'C']);
+/// ```
+///
+/// # See also
+/// [Longest common subsequence - Wikipedia](https://en.wikipedia.org/wiki/Longest_common_subsequence)
+pub fn longest_common_subsequence<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let n = a.len();
+    let m = b.len();
+
+    // lengths[i][j]: length of the LCS of a[..i] and b[..j]
+    let mut lengths: Vec<Vec<usize>> = vec![vec![0; m + 1]; n + 1];
+    for i in 1..=n {
+        for j in 1..=m {
+            lengths[i][j] = if a[i - 1] == b[j - 1] {
+                lengths[i - 1][j - 1] + 1
+            } else {
+                std::cmp::max(lengths[i - 1][j], lengths[i][j - 1])
+            };
+        }
+    }
+
+    // Backtrack from (n, m)
+    let mut subsequence: Vec<T> = Vec::with_capacity(lengths[n][m]);
+    let mut i = n;
+    let mut j = m;
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            subsequence.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if lengths[i - 1][j] >= lengths[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    subsequence.reverse();
+
+    subsequence
+}
+
+/// Computes the edit distance (Levenshtein distance) between two strings.
+///
+/// The distance is the minimum number of single-character insertions, deletions
+/// and substitutions needed to transform `a` into `b`. Only two rows of the dynamic
+/// programming table are kept, each sized after the shorter string.
+///
+/// # Arguments
+/// * `a` - First string.
+/// * `b` - Second string.
+///
+/// # Returns
+/// The edit distance between `a` and `b`, counted in characters.
+///
+/// # Complexity
+/// O(n * m) time, O(min(n, m)) memory.
+///
+/// # Example
+/// ```
+/// assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+/// ```
+///
+/// # See also
+/// [Levenshtein distance - Wikipedia](https://en.wikipedia.org/wiki/Levenshtein_distance)
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    // Rows are indexed by the shorter string
+    let (long, short) = if a_chars.len() >= b_chars.len() {
+        (a_chars, b_chars)
+    } else {
+        (b_chars, a_chars)
+    };
+
+    // previous_row[j]: distance between long[..i] and short[..j]
+    let mut previous_row: Vec<usize> = (0..=short.len()).collect();
+    let mut current_row: Vec<usize> = vec![0; short.len() + 1];
+
+    for i in 1..=long.len() {
+        current_row[0] = i;
+        for j in 1..=short.len() {
+            let substitution_cost = if long[i - 1] == short[j - 1] { 0 } else { 1 };
+            let deletion = previous_row[j] + 1;
+            let insertion = current_row[j - 1] + 1;
+            let substitution = previous_row[j - 1] + substitution_cost;
+            current_row[j] = std::cmp::min(std::cmp::min(deletion, insertion), substitution);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[short.len()]
+}
+
+/// Computes the failure function of the Knuth-Morris-Pratt algorithm.
+///
+/// `failure[i]` is the length of the longest proper prefix of `pattern[..=i]`
+/// which is also a suffix of it.
+fn kmp_failure_table(pattern: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0; pattern.len()];
+    let mut prefix_len = 0;
+    for i in 1..pattern.len() {
+        // Fall back to shorter borders until the next byte extends one
+        while prefix_len > 0 && pattern[i] != pattern[prefix_len] {
+            prefix_len = failure[prefix_len - 1];
+        }
+        if pattern[i] == pattern[prefix_len] {
+            prefix_len += 1;
+        }
+        failure[i] = prefix_len;
+    }
+    failure
+}
+
+/// Finds all the occurrences of a pattern in a text with the Knuth-Morris-Pratt algorithm.
+///
+/// After a mismatch, the failure function of `needle` tells how much of the current
+/// partial match can be kept, so the text is never scanned backwards.
+///
+/// # Arguments
+/// * `haystack` - The text to search in.
+/// * `needle` - The pattern to search for.
+///
+/// # Returns
+/// The byte indices where `needle` starts in `haystack`, in increasing order.
+/// Overlapping occurrences are all reported. An empty `needle` matches at every
+/// byte index from 0 to `haystack.len()` included.
+///
+/// # Complexity
+/// O(n + m) time, O(m) memory.
+///
+/// # Example
+/// ```
+/// assert_eq!(kmp_search("abcabab", "ab"), vec![0, 3, 5]);
+/// ```
+///
+/// # See also
+/// [Knuth-Morris-Pratt algorithm - Wikipedia](https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm)
+pub fn kmp_search(haystack: &str, needle: &str) -> Vec<usize> {
+    let text = haystack.as_bytes();
+    let pattern = needle.as_bytes();
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+
+    let failure = kmp_failure_table(pattern);
+    let mut matches = Vec::new();
+    let mut match_len = 0;
+    for (i, &byte) in text.iter().enumerate() {
+        while match_len > 0 && byte != pattern[match_len] {
+            match_len = failure[match_len - 1];
+        }
+        if byte == pattern[match_len] {
+            match_len += 1;
+        }
+        if match_len == pattern.len() {
+            matches.push(i + 1 - match_len);
+            // Keep the longest border to find overlapping occurrences
+            match_len = failure[match_len - 1];
+        }
+    }
+    matches
+}
+
+/// Generates all the permutations of a slice using Heap's algorithm.
+///
+/// Each permutation is obtained from the previous one by a single swap.
+///
+/// # Arguments
+/// * `items` - The elements to permute.
+///
+/// # Returns
+/// A vector containing the n! orderings of `items` (a single empty ordering if `items` is empty).
+///
+/// # Complexity
+/// O(n * n!)
+///
+/// # Example
+/// ```
+/// assert_eq!(permutations(&[1, 2, 3]).len(), 6);
+/// ```
+///
+/// # See also
+/// [Heap's algorithm - Wikipedia](https://en.wikipedia.org/wiki/Heap%27s_algorithm)
+pub fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let n = items.len();
+    let mut current: Vec<T> = items.to_vec();
+    let mut results: Vec<Vec<T>> = vec![current.clone()];
+
+    // Iterative version: counters[i] plays the role of the loop index at recursion depth i
+    let mut counters: Vec<usize> = vec![0; n];
+    let mut i = 1;
+    while i < n {
+        if counters[i] < i {
+            if i % 2 == 0 {
+                current.swap(0, i);
+            } else {
+                current.swap(counters[i], i);
+            }
+            results.push(current.clone());
+            counters[i] += 1;
+            i = 1;
+        } else {
+            counters[i] = 0;
+            i += 1;
+        }
+    }
+
+    results
+}
+
+/// Rearranges `items` into the next permutation in lexicographic order.
+///
+/// # Arguments
+/// * `items` - The elements to rearrange, in place.
+///
+/// # Returns
+/// `true` if the next permutation was produced, `false` if `items` was the last
+/// permutation, in which case it wraps around to the first one (sorted ascending).
+///
+/// # Complexity
+/// O(n)
+///
+/// # Example
+/// ```
+/// let mut items = [1, 2, 3];
+/// assert!(next_permutation(&mut items));
+/// assert_eq!(items, [1, 3, 2]);
+/// ```
+///
+/// # See also
+/// [Permutation - Wikipedia](https://en.wikipedia.org/wiki/Permutation#Generation_in_lexicographic_order)
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let n = items.len();
+    if n < 2 {
+        return false;
+    }
+
+    // Find the largest index i such that items[i] < items[i + 1]
+    let mut i = n - 1;
+    while i > 0 && items[i - 1] >= items[i] {
+        i -= 1;
+    }
+    if i == 0 {
+        // Last permutation (non-increasing order): wrap around to the first one
+        items.reverse();
+        return false;
+    }
+    let pivot = i - 1;
+
+    // Find the rightmost element greater than the pivot, swap, and reverse the suffix
+    let mut j = n - 1;
+    while items[j] <= items[pivot] {
+        j -= 1;
+    }
+    items.swap(pivot, j);
+    items[i..].reverse();
+
+    true
+}
+
+/// Generates all the combinations of `k` elements among `items`.
+///
+/// Combinations are produced in lexicographic order of their indices,
+/// by advancing an array of `k` increasing indices.
+///
+/// # Arguments
+/// * `items` - The elements to choose from.
+/// * `k` - The size of each combination.
+///
+/// # Returns
+/// A vector containing the C(n, k) combinations: a single empty combination if `k` = 0,
+/// no combination at all if `k` > n.
+///
+/// # Example
+/// ```
+/// assert_eq!(combinations(&[1, 2, 3], 2), vec![vec![1, 2], vec![1, 3], vec![2, 3]]);
+/// ```
+///
+/// # See also
+/// [Combination - Wikipedia](https://en.wikipedia.org/wiki/Combination)
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    let n = items.len();
+    let mut results: Vec<Vec<T>> = Vec::new();
+    if k > n {
+        return results;
+    }
+
+    let mut indices: Vec<usize> = (0..k).collect();
+    loop {
+        results.push(indices.iter().map(|&i| items[i].clone()).collect());
+
+        // Find the rightmost index that can still be incremented
+        let mut position = k;
+        while position > 0 && indices[position - 1] == n - k + position - 1 {
+            position -= 1;
+        }
+        if position == 0 {
+            break;
+        }
+
+        // Increment it, and reset the following indices just after it
+        indices[position - 1] += 1;
+        for i in position..k {
+            indices[i] = indices[i - 1] + 1;
+        }
+    }
+
+    results
+}
+
+/// Rotates an array to the left by `k` positions, in place.
+///
+/// Reversal algorithm: reverse the first `k` elements, reverse the remaining ones,
+/// then reverse the whole array. `k` is taken modulo the length of the array.
+///
+/// # Arguments
+/// * `array` - The array to rotate.
+/// * `k` - Number of positions.
+///
+/// # Complexity
+/// O(n) time, O(1) extra memory.
+///
+/// # Example
+/// ```
+/// let mut array = [1, 2, 3, 4, 5];
+/// rotate_left(&mut array, 2);
+/// assert_eq!(array, [3, 4, 5, 1, 2]);
+/// ```
+pub fn rotate_left<T>(array: &mut [T], k: usize) {
+    let n = array.len();
+    if n == 0 {
+        return;
+    }
+    let k = k % n;
+
+    array[..k].reverse();
+    array[k..].reverse();
+    array.reverse();
+}
+
+/// Rotates an array to the right by `k` positions, in place.
+///
+/// # Arguments
+/// * `array` - The array to rotate.
+/// * `k` - Number of positions.
+///
+/// # Complexity
+/// O(n) time, O(1) extra memory.
+///
+/// # Example
+/// ```
+/// let mut array = [1, 2, 3, 4, 5];
+/// rotate_right(&mut array, 2);
+/// assert_eq!(array, [4, 5, 1, 2, 3]);
+/// ```
+pub fn rotate_right<T>(array: &mut [T], k: usize) {
+    let n = array.len();
+    if n == 0 {
+        return;
+    }
+
+    // A right rotation by k is a left rotation by n - k
+    rotate_left(array, n - k % n);
+}
+
+/// Lists the elements of a matrix in clockwise spiral order, from the top-left corner.
+///
+/// The outer ring is traversed first (top row, right column, bottom row, left column),
+/// then the boundaries shrink and the next ring is traversed.
+///
+/// # Arguments
+/// * `matrix` - The matrix, as a list of rows of equal length.
+///
+/// # Returns
+/// All the elements, in spiral order (empty for an empty matrix).
+///
+/// # Panics
+/// Panics if the rows do not all have the same length.
+///
+/// # Example
+/// ```
+/// let matrix = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+/// assert_eq!(spiral_order(&matrix), vec![1, 2, 3, 6, 9, 8, 7, 4, 5]);
+/// ```
+///
+/// # Complexity
+/// O(rows * cols).
+pub fn spiral_order<T: Clone>(matrix: &[Vec<T>]) -> Vec<T> {
+    let mut result: Vec<T> = Vec::new();
+    if matrix.is_empty() {
+        return result;
+    }
+    let cols = matrix[0].len();
+    assert!(
+        matrix.iter().all(|row| row.len() == cols),
+        "Error in spiral_order: all rows must have the same length."
+    );
+
+    // Boundaries of the remaining ring (bottom and right are exclusive)
+    let (mut top, mut bottom, mut left, mut right) = (0, matrix.len(), 0, cols);
+    while top < bottom && left < right {
+        result.extend_from_slice(&matrix[top][left..right]);
+        top += 1;
+
+        for row in &matrix[top..bottom] {
+            result.push(row[right - 1].clone());
+        }
+        right -= 1;
+
+        // A single remaining row or column has already been traversed
+        if top < bottom {
+            result.extend(matrix[bottom - 1][left..right].iter().rev().cloned());
+            bottom -= 1;
+        }
+        if left < right {
+            for row in matrix[top..bottom].iter().rev() {
+                result.push(row[left].clone());
+            }
+            left += 1;
+        }
+    }
+
+    result
+}
+
+/// Rotates a square matrix by 90 degrees clockwise, in place.
+///
+/// The matrix is transposed (swapping elements across the main diagonal),
+/// then each row is reversed.
+///
+/// # Arguments
+/// * `matrix` - The square matrix, as a list of rows.
+///
+/// # Panics
+/// Panics if the matrix is not square.
+///
+/// # Example
+/// ```
+/// let mut matrix = vec![vec![1, 2], vec![3, 4]];
+/// rotate_matrix_90(&mut matrix);
+/// assert_eq!(matrix, vec![vec![3, 1], vec![4, 2]]);
+/// ```
+///
+/// # Complexity
+/// O(n²) time, O(1) extra memory.
+pub fn rotate_matrix_90<T: Clone>(matrix: &mut [Vec<T>]) {
+    let n = matrix.len();
+    assert!(
+        matrix.iter().all(|row| row.len() == n),
+        "Error in rotate_matrix_90: the matrix must be square."
+    );
+
+    // Transpose: swap each element above the diagonal with its mirror below
+    for i in 0..n {
+        let (upper_rows, lower_rows) = matrix.split_at_mut(i + 1);
+        for (offset, lower_row) in lower_rows.iter_mut().enumerate() {
+            std::mem::swap(&mut upper_rows[i][i + 1 + offset], &mut lower_row[i]);
+        }
+    }
+
+    for row in matrix.iter_mut() {
+        row.reverse();
+    }
+}
+
+/// Compresses a sequence with run-length encoding.
+///
+/// # Arguments
+/// * `data` - The sequence to encode.
+///
+/// # Returns
+/// The runs of equal consecutive elements, as (element, run length) pairs.
+///
+/// # Example
+/// ```
+/// let encoded = run_length_encode(&['a', 'a', 'a', 'b', 'c', 'c']);
+/// assert_eq!(encoded, vec![('a', 3), ('b', 1), ('c', 2)]);
+/// ```
+///
+/// # See also
+/// [Run-length encoding - Wikipedia](https://en.wikipedia.org/wiki/Run-length_encoding)
+pub fn run_length_encode<T: PartialEq + Clone>(data: &[T]) -> Vec<(T, usize)> {
+    let mut encoded: Vec<(T, usize)> = Vec::new();
+    for elem in data {
+        match encoded.last_mut() {
+            Some((value, count)) if value == elem => *count += 1,
+            _ => encoded.push((elem.clone(), 1)),
+        }
+    }
+    encoded
+}
+
+/// Decompresses a run-length encoded sequence.
+///
+/// # Arguments
+/// * `encoded` - The (element, run length) pairs.
+///
+/// # Returns
+/// The decoded sequence.
+///
+/// # Example
+/// ```
+/// assert_eq!(run_length_decode(&[('a', 2), ('b', 1)]), vec!['a', 'a', 'b']);
+/// ```
+pub fn run_length_decode<T: Clone>(encoded: &[(T, usize)]) -> Vec<T> {
+    let mut decoded: Vec<T> = Vec::new();
+    for (value, count) in encoded {
+        decoded.extend(std::iter::repeat_n(value.clone(), *count));
+    }
+    decoded
+}
+
+/// Generates all the subsets of a slice (its power set).
+///
+/// Subset number `mask` contains the elements `items[i]` for which bit `i` of `mask`
+/// is set, for `mask` from 0 to 2^n - 1: the empty set comes first.
+///
+/// # Arguments
+/// * `items` - The elements of the set.
+///
+/// # Returns
+/// A vector containing the 2^n subsets of `items`.
+///
+/// # Panics
+/// Panics if `items` has 64 elements or more (the subsets could not be enumerated
+/// with a u64 mask, nor stored anyway).
+///
+/// # Complexity
+/// O(n * 2^n)
+///
+/// # Example
+/// ```
+/// assert_eq!(power_set(&[1, 2]), vec![vec![], vec![1], vec![2], vec![1, 2]]);
+/// ```
+///
+/// # See also
+/// [Power set - Wikipedia](https://en.wikipedia.org/wiki/Power_set)
+pub fn power_set<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let n = items.len();
+    assert!(
+        n < 64,
+        "Error in power_set: too many items ({}), 2^n subsets would overflow",
+        n
+    );
+
+    let subset_count: u64 = 1 << n;
+    let mut subsets: Vec<Vec<T>> = Vec::new();
+    for mask in 0..subset_count {
+        let subset: Vec<T> = items
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (mask >> i) & 1 == 1)
+            .map(|(_, elem)| elem.clone())
+            .collect();
+        subsets.push(subset);
+    }
+    subsets
+}
+
+/// Above this number of cells, `subset_sum` does not allocate its dynamic programming table.
+const SUBSET_SUM_MAX_TABLE_SIZE: usize = 1 << 26;
+
+/// Backtracking search for a subset of `items[index..]` summing to `target`.
+fn subset_sum_backtracking(
+    items: &[i64],
+    index: usize,
+    target: i64,
+    chosen_indices: &mut Vec<usize>,
+) -> bool {
+    if index == items.len() {
+        return target == 0;
+    }
+
+    // Either take items[index] (unless the remaining target overflows), or skip it
+    if let Some(remaining) = target.checked_sub(items[index]) {
+        chosen_indices.push(index);
+        if subset_sum_backtracking(items, index + 1, remaining, chosen_indices) {
+            return true;
+        }
+        chosen_indices.pop();
+    }
+    subset_sum_backtracking(items, index + 1, target, chosen_indices)
+}
+
+/// Finds a subset of items whose sum equals `target`.
+///
+/// For non-negative items and target, uses dynamic programming: `reachable[i][t]` tells
+/// whether a sum `t` can be obtained with the first `i` items. Otherwise (signed items, or
+/// a table larger than `SUBSET_SUM_MAX_TABLE_SIZE` cells), falls back to an exhaustive
+/// backtracking search.
+///
+/// # Arguments
+/// * `items` - The values to choose from.
+/// * `target` - The sum to reach.
+///
+/// # Returns
+/// The indices (ascending) of a subset summing to `target`, or None if there is none.
+/// A target of 0 is always reached by the empty subset.
+///
+/// # Complexity
+/// O(n * target) for non-negative inputs with a small enough target, O(2^n) otherwise.
+///
+/// # Example
+/// ```
+/// assert_eq!(subset_sum(&[3, 34, 4, 12, 5, 2], 9), Some(vec![2, 4]));
+/// ```
+///
+/// # See also
+/// [Subset sum problem - Wikipedia](https://en.wikipedia.org/wiki/Subset_sum_problem)
+pub fn subset_sum(items: &[i64], target: i64) -> Option<Vec<usize>> {
+    if target == 0 {
+        return Some(Vec::new());
+    }
+
+    let backtracking = || {
+        let mut chosen_indices: Vec<usize> = Vec::new();
+        if subset_sum_backtracking(items, 0, target, &mut chosen_indices) {
+            Some(chosen_indices)
+        } else {
+            None
+        }
+    };
+
+    let all_non_negative = items.iter().all(|&item| item >= 0);
+    if !all_non_negative {
+        return backtracking();
+    }
+
+    let total = items
+        .iter()
+        .fold(0i64, |acc, &item| acc.saturating_add(item));
+    if target < 0 || target > total {
+        return None;
+    }
+
+    let n = items.len();
+    let target = target as usize;
+    match (n + 1).checked_mul(target + 1) {
+        Some(table_size) if table_size <= SUBSET_SUM_MAX_TABLE_SIZE => {}
+        _ => return backtracking(),
+    }
+    let mut reachable: Vec<Vec<bool>> = vec![vec![false; target + 1]; n + 1];
+    reachable[0][0] = true;
+    for i in 1..=n {
+        let item = items[i - 1] as usize;
+        for t in 0..=target {
+            reachable[i][t] = reachable[i - 1][t] || (item <= t && reachable[i - 1][t - item]);
+        }
+    }
+
+    if !reachable[n][target] {
+        return None;
+    }
+
+    // Backtrack: take an item whenever the sum is not reachable without it
+    let mut chosen_indices: Vec<usize> = Vec::new();
+    let mut t = target;
+    for i in (1..=n).rev() {
+        if !reachable[i - 1][t] {
+            chosen_indices.push(i - 1);
+            t -= items[i - 1] as usize;
+        }
+    }
+    chosen_indices.reverse();
+
+    Some(chosen_indices)
+}
+
+/// Below this number of digits, `karatsuba_multiply` falls back to schoolbook multiplication.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Removes the most significant zero digits (zero becomes the empty vector).
+fn trim_digits(digits: &mut Vec<u8>) {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+}
+
+/// Adds `x * 10^shift` to `result`, digits being least significant first.
+fn add_digits_shifted(result: &mut Vec<u8>, x: &[u8], shift: usize) {
+    if result.len() < x.len() + shift {
+        result.resize(x.len() + shift, 0);
+    }
+    let mut carry = 0;
+    let mut i = shift;
+    while i - shift < x.len() || carry > 0 {
+        if i == result.len() {
+            result.push(0);
+        }
+        let sum = result[i] + x.get(i - shift).copied().unwrap_or(0) + carry;
+        result[i] = sum % 10;
+        carry = sum / 10;
+        i += 1;
+    }
+}
+
+/// Subtracts `x` from `result` in place, assuming `result >= x`.
+fn sub_digits(result: &mut Vec<u8>, x: &[u8]) {
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < x.len() || borrow > 0 {
+        let subtrahend = x.get(i).copied().unwrap_or(0) + borrow;
+        if result[i] >= subtrahend {
+            result[i] -= subtrahend;
+            borrow = 0;
+        } else {
+            result[i] = result[i] + 10 - subtrahend;
+            borrow = 1;
+        }
+        i += 1;
+    }
+    trim_digits(result);
+}
+
+/// Multiplies two digit vectors with the O(n * m) schoolbook method.
+fn schoolbook_multiply(a: &[u8], b: &[u8]) -> Vec<u8> {
+    // Accumulate on u32 to delay carry propagation
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &a_digit) in a.iter().enumerate() {
+        for (j, &b_digit) in b.iter().enumerate() {
+            product[i + j] += (a_digit as u32) * (b_digit as u32);
+        }
+        // Propagate carries once per row, so that the accumulators never overflow
+        let mut carry = 0;
+        for digit in product[i..].iter_mut() {
+            *digit += carry;
+            carry = *digit / 10;
+            *digit %= 10;
+        }
+    }
+    let mut product: Vec<u8> = product.into_iter().map(|digit| digit as u8).collect();
+    trim_digits(&mut product);
+    product
+}
+
+/// Recursive step of `karatsuba_multiply`, on trimmed digit vectors (zero being empty).
+fn karatsuba_multiply_trimmed(a: &[u8], b: &[u8]) -> Vec<u8> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook_multiply(a, b);
+    }
+
+    // Split a = a1 * 10^m + a0 and b = b1 * 10^m + b0 (a1 or b1 is zero for a much shorter factor)
+    let m = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let (b0, b1) = b.split_at(m.min(b.len()));
+    let mut a0 = a0.to_vec();
+    let mut b0 = b0.to_vec();
+    trim_digits(&mut a0);
+    trim_digits(&mut b0);
+
+    let z0 = karatsuba_multiply_trimmed(&a0, &b0);
+    let z2 = karatsuba_multiply_trimmed(a1, b1);
+
+    // z1 = (a0 + a1)(b0 + b1) - z0 - z2 = a0 * b1 + a1 * b0
+    add_digits_shifted(&mut a0, a1, 0);
+    add_digits_shifted(&mut b0, b1, 0);
+    let mut z1 = karatsuba_multiply_trimmed(&a0, &b0);
+    sub_digits(&mut z1, &z0);
+    sub_digits(&mut z1, &z2);
+
+    // Result: z2 * 10^(2m) + z1 * 10^m + z0
+    let mut product = z0;
+    add_digits_shifted(&mut product, &z1, m);
+    add_digits_shifted(&mut product, &z2, 2 * m);
+    trim_digits(&mut product);
+    product
+}
+
+/// Multiplies two big integers with the Karatsuba algorithm.
+///
+/// The integers are given as base-10 digits, least significant first, so that
+/// products exceeding `u64` can be computed. Each number is split in two halves
+/// `x = x1 * 10^m + x0`, and the product is obtained from three recursive products
+/// instead of four: `x0 * y0`, `x1 * y1` and `(x0 + x1)(y0 + y1)`.
+///
+/// # Arguments
+/// * `a` - The first factor, as digits in 0..=9, least significant first.
+/// * `b` - The second factor, same representation.
+///
+/// # Returns
+/// The product, as digits least significant first, without leading zeros
+/// (zero is represented by `vec![0]`).
+///
+/// # Panics
+/// Panics if a digit is greater than 9.
+///
+/// # Complexity
+/// O(n^log2(3)) ≈ O(n^1.585) for two n-digit numbers.
+///
+/// # Example
+/// ```
+/// assert_eq!(karatsuba_multiply(&[2, 1], &[4, 3]), vec![8, 0, 4]); // 12 * 34 = 408
+/// ```
+///
+/// # See also
+/// [Karatsuba algorithm - Wikipedia](https://en.wikipedia.org/wiki/Karatsuba_algorithm)
+pub fn karatsuba_multiply(a: &[u8], b: &[u8]) -> Vec<u8> {
+    assert!(
+        a.iter().chain(b).all(|&digit| digit <= 9),
+        "Error in karatsuba_multiply: digits must be in 0..=9."
+    );
+
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    trim_digits(&mut a);
+    trim_digits(&mut b);
+
+    let product = karatsuba_multiply_trimmed(&a, &b);
+    if product.is_empty() {
+        vec![0]
+    } else {
+        product
+    }
+}
+
+/// Finds the majority element of a slice with the Boyer-Moore voting algorithm.
+///
+/// A candidate is kept with a counter, incremented when the candidate is met again and
+/// decremented otherwise. A majority element is necessarily the final candidate, but the
+/// final candidate is not necessarily a majority, hence a second verification pass.
+///
+/// # Arguments
+/// * `array` - The elements.
+///
+/// # Returns
+/// `Some(element)` if it appears more than `array.len() / 2` times, `None` otherwise.
+///
+/// # Complexity
+/// O(n) time, O(1) memory.
+///
+/// # Example
+/// ```
+/// assert_eq!(majority_element(&[2, 2, 1, 2, 3, 2]), Some(2));
+/// assert_eq!(majority_element(&[1, 2, 3]), None);
+/// ```
+///
+/// # See also
+/// [Boyer-Moore majority vote algorithm - Wikipedia](https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore_majority_vote_algorithm)
+pub fn majority_element<T: PartialEq + Clone>(array: &[T]) -> Option<T> {
+    let mut candidate: Option<&T> = None;
+    let mut count = 0;
+    for elem in array {
+        if count == 0 {
+            candidate = Some(elem);
+            count = 1;
+        } else if candidate == Some(elem) {
+            count += 1;
+        } else {
+            count -= 1;
+        }
+    }
+
+    // Verification pass
+    let candidate = candidate?;
+    let occurrences = array.iter().filter(|&elem| elem == candidate).count();
+    if 2 * occurrences > array.len() {
+        Some(candidate.clone())
+    } else {
+        None
+    }
+}
+
+/// Finds the contiguous subarray with the largest sum, with Kadane's algorithm.
+///
+/// For each end index, the best subarray ending there either extends the best one ending
+/// at the previous index, or starts anew when that one has a negative sum.
+///
+/// # Arguments
+/// * `array` - The values (must be non-empty).
+///
+/// # Returns
+/// A tuple (sum, start, end): the maximum sum, reached by `array[start..=end]`.
+/// For an all-negative array, this is the largest single element.
+///
+/// # Panics
+/// Panics if `array` is empty.
+///
+/// # Complexity
+/// O(n) time, O(1) memory.
+///
+/// # Example
+/// ```
+/// assert_eq!(max_subarray_sum(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]), (6, 3, 6));
+/// ```
+///
+/// # See also
+/// [Maximum subarray problem - Wikipedia](https://en.wikipedia.org/wiki/Maximum_subarray_problem)
+pub fn max_subarray_sum(array: &[i64]) -> (i64, usize, usize) {
+    assert!(
+        !array.is_empty(),
+        "Error in max_subarray_sum: the array must be non-empty."
+    );
+
+    let mut best = (array[0], 0, 0);
+    // Best subarray ending at the current index
+    let mut current_sum = array[0];
+    let mut current_start = 0;
+
+    for (i, &value) in array.iter().enumerate().skip(1) {
+        if current_sum < 0 {
+            current_sum = value;
+            current_start = i;
+        } else {
+            current_sum += value;
+        }
+        if current_sum > best.0 {
+            best = (current_sum, current_start, i);
+        }
+    }
+
+    best
+}
+
+/// Tests whether two strings are anagrams of each other.
+///
+/// Letter case and whitespace are ignored, so that "Dormitory" and "dirty room" are anagrams.
+/// Other characters (punctuation, digits) are compared as is.
+///
+/// # Arguments
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Returns
+/// `true` if both strings contain the same characters with the same multiplicities.
+///
+/// # Complexity
+/// O(n log n), the normalized characters of each string being sorted.
+///
+/// # Example
+/// ```
+/// assert!(are_anagrams("listen", "silent"));
+/// assert!(!are_anagrams("hello", "world"));
+/// ```
+///
+/// # See also
+/// [Anagram - Wikipedia](https://en.wikipedia.org/wiki/Anagram)
+pub fn are_anagrams(a: &str, b: &str) -> bool {
+    let normalized_sorted_chars = |s: &str| {
+        let mut chars: Vec<char> = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect();
+        chars.sort_unstable();
+        chars
+    };
+
+    normalized_sorted_chars(a) == normalized_sorted_chars(b)
+}
+
+/// Tests whether a string reads the same backwards.
+///
+/// # Arguments
+/// * `s` - The string.
+/// * `ignore_case_and_punctuation` - If `true`, letter case is ignored and only alphanumeric
+///   characters are compared, so that "A man, a plan, a canal: Panama" is a palindrome.
+///
+/// # Returns
+/// `true` if the (normalized) characters of `s` form a palindrome.
+///
+/// # Example
+/// ```
+/// assert!(is_palindrome_str("kayak", false));
+/// assert!(is_palindrome_str("Was it a car or a cat I saw?", true));
+/// ```
+///
+/// # See also
+/// [Palindrome - Wikipedia](https://en.wikipedia.org/wiki/Palindrome)
+pub fn is_palindrome_str(s: &str, ignore_case_and_punctuation: bool) -> bool {
+    let chars: Vec<char> = if ignore_case_and_punctuation {
+        s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    } else {
+        s.chars().collect()
+    };
+
+    chars.iter().eq(chars.iter().rev())
+}
+
+/// Tests whether the decimal digits of a number read the same backwards.
+///
+/// # Arguments
+/// * `n` - The number.
+///
+/// # Returns
+/// `true` if `n` is a palindrome in base 10.
+///
+/// # Example
+/// ```
+/// assert!(is_palindrome_number(12321));
+/// assert!(!is_palindrome_number(123));
+/// ```
+pub fn is_palindrome_number(n: u64) -> bool {
+    // Reverse the digits on u128, as the reverse of a u64 may overflow
+    let mut reversed: u128 = 0;
+    let mut remaining = n;
+    while remaining > 0 {
+        reversed = reversed * 10 + (remaining % 10) as u128;
+        remaining /= 10;
+    }
+    reversed == n as u128
+}
+
+/// Computes the binomial coefficient C(n, k), the number of k-element subsets of an n-element set.
+///
+/// Uses the multiplicative formula C(n, i) = C(n, i - 1) * (n - k + i) / i for i in 1..=k,
+/// dividing by the gcd before multiplying, so that intermediate values never exceed the result.
+///
+/// # Arguments
+/// * `n` - The size of the set.
+/// * `k` - The size of the subsets.
+///
+/// # Returns
+/// C(n, k), or 0 if `k > n`.
+///
+/// # Panics
+/// Panics if the result overflows u128.
+///
+/// # Complexity
+/// O(min(k, n - k)) multiplications and gcd computations.
+///
+/// # Example
+/// ```
+/// assert_eq!(binomial(5, 2), 10);
+/// ```
+///
+/// # See also
+/// [Binomial coefficient - Wikipedia](https://en.wikipedia.org/wiki/Binomial_coefficient#Multiplicative_formula)
+pub fn binomial(n: u64, k: u64) -> u128 {
+    if k > n {
+        return 0;
+    }
+    // Symmetry: C(n, k) = C(n, n - k)
+    let k = k.min(n - k);
+
+    let mut result: u128 = 1;
+    for i in 1..=k {
+        // result * factor / i is an integer (it is C(n - k + i, i)),
+        // and i / g is coprime with result / g, so i / g divides factor
+        let factor = (n - k + i) as u128;
+        let g = gcd((result % i as u128) as u64, i) as u128;
+        result = (result / g)
+            .checked_mul(factor / (i as u128 / g))
+            .expect("Error in binomial: overflow.");
+    }
+    result
+}
+
+/// Computes the nth Catalan number, C(2n, n) / (n + 1).
+///
+/// Catalan numbers count, among others, the well-parenthesized expressions with n pairs
+/// of parentheses and the binary trees with n internal nodes.
+///
+/// # Arguments
+/// * `n` - The index.
+///
+/// # Returns
+/// The nth Catalan number.
+///
+/// # Panics
+/// Panics if `2 * n` overflows u64, or if C(2n, n) overflows u128 (from n = 66 on).
+///
+/// # Example
+/// ```
+/// assert_eq!(catalan(4), 14);
+/// ```
+///
+/// # See also
+/// [Catalan number - Wikipedia](https://en.wikipedia.org/wiki/Catalan_number)
+pub fn catalan(n: u64) -> u128 {
+    let two_n = n.checked_mul(2).expect("Error in binomial: overflow.");
+    binomial(two_n, n) / (n as u128 + 1)
+}
+
+/// Returns the index of the smallest element of a slice.
+///
+/// Elements that are not comparable with themselves (such as NaN) are skipped.
+///
+/// # Arguments
+/// * `slice` - The elements.
+///
+/// # Returns
+/// `Some(index)` of the first smallest element, `None` if `slice` is empty
+/// or has no comparable element.
+///
+/// # Example
+/// ```
+/// assert_eq!(argmin(&[3.0, 1.0, 4.0, 1.0, 5.0]), Some(1));
+/// ```
+pub fn argmin<T: PartialOrd>(slice: &[T]) -> Option<usize> {
+    // Seed with the first element comparable with itself, so that a leading NaN is not kept
+    let mut min_index = slice
+        .iter()
+        .position(|elem| elem.partial_cmp(elem).is_some())?;
+    for (i, elem) in slice.iter().enumerate().skip(min_index + 1) {
+        // Strict comparison: the first index wins on ties
+        if *elem < slice[min_index] {
+            min_index = i;
+        }
+    }
+    Some(min_index)
+}
+
+/// Returns the index of the largest element of a slice.
+///
+/// Elements that are not comparable with themselves (such as NaN) are skipped.
+///
+/// # Arguments
+/// * `slice` - The elements.
+///
+/// # Returns
+/// `Some(index)` of the first largest element, `None` if `slice` is empty
+/// or has no comparable element.
+///
+/// # Example
+/// ```
+/// assert_eq!(argmax(&[3.0, 1.0, 4.0, 1.0, 5.0]), Some(4));
+/// ```
+pub fn argmax<T: PartialOrd>(slice: &[T]) -> Option<usize> {
+    // Seed with the first element comparable with itself, so that a leading NaN is not kept
+    let mut max_index = slice
+        .iter()
+        .position(|elem| elem.partial_cmp(elem).is_some())?;
+    for (i, elem) in slice.iter().enumerate().skip(max_index + 1) {
+        // Strict comparison: the first index wins on ties
+        if *elem > slice[max_index] {
+            max_index = i;
+        }
+    }
+    Some(max_index)
+}
+
+/// Restricts a value to the range [min, max].
+///
+/// Unlike `Ord::clamp`, only PartialOrd is required, so that it applies to floats.
+///
+/// # Arguments
+/// * `value` - The value to restrict.
+/// * `min` - The lower bound.
+/// * `max` - The upper bound.
+///
+/// # Returns
+/// `min` if `value < min`, `max` if `value > max`, `value` otherwise.
+///
+/// # Panics
+/// Panics if `min > max`.
+///
+/// # Example
+/// ```
+/// assert_eq!(clamp(1.5, 0., 1.), 1.);
+/// ```
+pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    assert!(min <= max, "Error in clamp: min must be <= max.");
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Linear interpolation between two values.
+///
+/// # Arguments
+/// * `a` - The value at `t = 0`.
+/// * `b` - The value at `t = 1`.
+/// * `t` - The interpolation parameter (values outside [0, 1] extrapolate).
+///
+/// # Returns
+/// `a + (b - a) * t`
+///
+/// # Example
+/// ```
+/// assert_eq!(lerp(2., 4., 0.5), 3.);
+/// ```
+///
+/// # See also
+/// [Linear interpolation - Wikipedia](https://en.wikipedia.org/wiki/Linear_interpolation)
+pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
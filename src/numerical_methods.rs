@@ -0,0 +1,486 @@
+//! Numerical Methods
+//!
+//! Small numerical toolbox:
+//! - Generic dense matrix type (multiplication, transposition)
+//! - Linear systems (Gaussian elimination with partial pivoting)
+//! - Polynomials (Horner evaluation, derivative)
+//! - Root finding (Newton-Raphson, bisection)
+//! - Numerical integration (composite Simpson's rule)
+
+#![allow(dead_code)]
+
+use std::ops::{Add, Mul};
+
+/// Dense matrix stored in row-major order.
+///
+/// # Type Parameters
+/// * `T` - Element type (must support addition, multiplication, cloning and a default (zero) value)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Clone + Default,
+{
+    /// Creates a matrix filled with the default value of `T` (zero for numeric types).
+    ///
+    /// # Arguments
+    /// * `rows` - Number of rows
+    /// * `cols` - Number of columns
+    ///
+    /// # Example
+    /// ```
+    /// let m: Matrix<f64> = Matrix::new(2, 3);
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![T::default(); rows * cols],
+        }
+    }
+
+    /// Creates a matrix from its elements, given row by row.
+    ///
+    /// # Arguments
+    /// * `rows` - Number of rows
+    /// * `cols` - Number of columns
+    /// * `data` - The `rows * cols` elements, in row-major order
+    ///
+    /// # Panics
+    /// Panics if `data` does not contain exactly `rows * cols` elements.
+    ///
+    /// # Example
+    /// ```
+    /// let m = Matrix::from_vec(2, 2, vec![1, 2, 3, 4]);
+    /// assert_eq!(*m.get(1, 0), 3);
+    /// ```
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "Error in Matrix::from_vec: expected rows * cols elements"
+        );
+        Matrix { rows, cols, data }
+    }
+
+    /// Creates the `n` x `n` identity matrix.
+    ///
+    /// # Example
+    /// ```
+    /// let id: Matrix<i64> = Matrix::identity(3);
+    /// ```
+    pub fn identity(n: usize) -> Self
+    where
+        T: From<u8>,
+    {
+        let mut matrix = Self::new(n, n);
+        for i in 0..n {
+            matrix.set(i, i, T::from(1u8));
+        }
+        matrix
+    }
+
+    /// Gets the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Gets the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Gets the element at (`row`, `col`).
+    ///
+    /// # Panics
+    /// Panics if the position is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "Error in Matrix::get: position out of bounds"
+        );
+        &self.data[row * self.cols + col]
+    }
+
+    /// Sets the element at (`row`, `col`).
+    ///
+    /// # Panics
+    /// Panics if the position is out of bounds.
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        assert!(
+            row < self.rows && col < self.cols,
+            "Error in Matrix::set: position out of bounds"
+        );
+        self.data[row * self.cols + col] = value;
+    }
+
+    /// Computes the matrix product `self * other`.
+    ///
+    /// # Arguments
+    /// * `other` - Right-hand side matrix
+    ///
+    /// # Returns
+    /// The product, or an error if the number of columns of `self` differs
+    /// from the number of rows of `other`.
+    ///
+    /// # Complexity
+    /// O(n * m * p) for a (n x m) by (m x p) product
+    ///
+    /// # Example
+    /// ```
+    /// let a = Matrix::from_vec(1, 2, vec![1, 2]);
+    /// let b = Matrix::from_vec(2, 1, vec![3, 4]);
+    /// assert_eq!(a.multiply(&b), Ok(Matrix::from_vec(1, 1, vec![11])));
+    /// ```
+    ///
+    /// # Reference
+    /// [Matrix multiplication - Wikipedia](https://en.wikipedia.org/wiki/Matrix_multiplication)
+    pub fn multiply(&self, other: &Matrix<T>) -> Result<Matrix<T>, String> {
+        if self.cols != other.rows {
+            return Err(format!(
+                "Dimension mismatch: cannot multiply a {}x{} matrix by a {}x{} matrix",
+                self.rows, self.cols, other.rows, other.cols
+            ));
+        }
+
+        let mut product = Matrix::new(self.rows, other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let mut sum = T::default();
+                for k in 0..self.cols {
+                    sum = sum + self.get(i, k).clone() * other.get(k, j).clone();
+                }
+                product.set(i, j, sum);
+            }
+        }
+        Ok(product)
+    }
+
+    /// Computes the transpose of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// let m = Matrix::from_vec(1, 2, vec![1, 2]);
+    /// assert_eq!(m.transpose(), Matrix::from_vec(2, 1, vec![1, 2]));
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut transposed = Matrix::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                transposed.set(j, i, self.get(i, j).clone());
+            }
+        }
+        transposed
+    }
+}
+
+/// Finds a root of `f` with Newton's method.
+///
+/// Iterates `x <- x - f(x) / f'(x)` from `x0`, until `|f(x)| < tolerance`.
+///
+/// # Arguments
+/// * `f` - The function
+/// * `f_prime` - Its derivative
+/// * `x0` - Initial guess
+/// * `tolerance` - Convergence threshold on `|f(x)|`
+/// * `max_iter` - Maximum number of iterations
+///
+/// # Returns
+/// `Some(x)` with `|f(x)| < tolerance`, or None if the method did not converge within
+/// `max_iter` iterations or hit a (near) zero derivative.
+///
+/// # Complexity
+/// Quadratic convergence near a simple root
+///
+/// # Example
+/// ```
+/// let sqrt_2 = newton_raphson(|x| x * x - 2., |x| 2. * x, 1., 1e-12, 50).unwrap();
+/// ```
+///
+/// # Reference
+/// [Newton's method - Wikipedia](https://en.wikipedia.org/wiki/Newton%27s_method)
+pub fn newton_raphson(
+    f: impl Fn(f64) -> f64,
+    f_prime: impl Fn(f64) -> f64,
+    x0: f64,
+    tolerance: f64,
+    max_iter: usize,
+) -> Option<f64> {
+    let mut x = x0;
+    for _ in 0..max_iter {
+        let f_x = f(x);
+        if f_x.abs() < tolerance {
+            return Some(x);
+        }
+
+        let f_prime_x = f_prime(x);
+        if f_prime_x.abs() < f64::EPSILON {
+            // Horizontal tangent: the next iterate is undefined
+            return None;
+        }
+        x -= f_x / f_prime_x;
+    }
+
+    if f(x).abs() < tolerance {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Integrates `f` over [a, b] with the composite Simpson's rule.
+///
+/// The interval is split into `n` subintervals of width `h`, and the integral is
+/// approximated by `h / 3 * (f(x0) + 4 f(x1) + 2 f(x2) + 4 f(x3) + ... + 4 f(x(n-1)) + f(xn))`.
+///
+/// # Arguments
+/// * `f` - The function to integrate
+/// * `a`, `b` - Integration bounds
+/// * `n` - Number of subintervals (rounded up to the next even number if odd)
+///
+/// # Returns
+/// An approximation of the integral of `f` from `a` to `b`.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Complexity
+/// O(n) evaluations of `f`, error in O(h^4) for smooth functions
+///
+/// # Example
+/// ```
+/// let integral = simpson_integrate(|x| x * x, 0., 1., 100); // ~ 1/3
+/// ```
+///
+/// # Reference
+/// [Simpson's rule - Wikipedia](https://en.wikipedia.org/wiki/Simpson%27s_rule#Composite_Simpson's_1/3_rule)
+pub fn simpson_integrate(f: impl Fn(f64) -> f64, a: f64, b: f64, n: usize) -> f64 {
+    assert_ne!(n, 0, "Error in simpson_integrate: n must be non-zero.");
+    // Simpson's rule works on pairs of subintervals
+    let n = n + n % 2;
+
+    let h = (b - a) / (n as f64);
+    let mut sum = f(a) + f(b);
+    for i in 1..n {
+        let weight = if i % 2 == 1 { 4. } else { 2. };
+        sum += weight * f(a + (i as f64) * h);
+    }
+
+    sum * h / 3.
+}
+
+/// Solves the linear system `a * x = b` by Gaussian elimination with partial pivoting.
+///
+/// At each step, the row with the largest pivot (in absolute value) is swapped into place,
+/// which keeps the elimination numerically stable. The triangular system is then solved
+/// by back-substitution.
+///
+/// # Arguments
+/// * `a` - Square matrix of the system
+/// * `b` - Right-hand side, with one value per row of `a`
+///
+/// # Returns
+/// `Some(x)`, or None if the matrix is singular (a pivot is zero, relative to the
+/// magnitude of the entries of `a`).
+///
+/// # Panics
+/// Panics if `a` is not square, or if `b` does not have one value per row of `a`.
+///
+/// # Complexity
+/// O(n^3) for a n x n system
+///
+/// # Example
+/// ```
+/// let a = Matrix::from_vec(2, 2, vec![2., 1., 1., 3.]);
+/// let x = solve_linear_system(&a, &[3., 5.]).unwrap(); // x ~ [0.8, 1.4]
+/// ```
+///
+/// # Reference
+/// [Gaussian elimination - Wikipedia](https://en.wikipedia.org/wiki/Gaussian_elimination)
+pub fn solve_linear_system(a: &Matrix<f64>, b: &[f64]) -> Option<Vec<f64>> {
+    assert_eq!(
+        a.rows, a.cols,
+        "Error in solve_linear_system: the matrix must be square"
+    );
+    assert_eq!(
+        b.len(),
+        a.rows,
+        "Error in solve_linear_system: expected one right-hand side value per row"
+    );
+    let n = a.rows;
+
+    // Pivots below this threshold are considered zero
+    let scale = a.data.iter().fold(0., |max: f64, x| max.max(x.abs()));
+    let threshold = scale * (n as f64) * f64::EPSILON;
+
+    // Augmented matrix [a | b], row by row
+    let mut rows: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            let mut row = a.data[i * n..(i + 1) * n].to_vec();
+            row.push(b[i]);
+            row
+        })
+        .collect();
+
+    // Forward elimination
+    for col in 0..n {
+        let pivot_row = (col..n)
+            .max_by(|&i, &j| rows[i][col].abs().total_cmp(&rows[j][col].abs()))
+            .unwrap();
+        if rows[pivot_row][col].abs() <= threshold {
+            return None;
+        }
+        rows.swap(col, pivot_row);
+
+        // Eliminate the column below the pivot
+        let (upper_rows, lower_rows) = rows.split_at_mut(col + 1);
+        let pivot = &upper_rows[col];
+        for row in lower_rows {
+            let factor = row[col] / pivot[col];
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot[col..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    // Back-substitution
+    let mut x = vec![0.; n];
+    for i in (0..n).rev() {
+        let sum: f64 = ((i + 1)..n).map(|j| rows[i][j] * x[j]).sum();
+        x[i] = (rows[i][n] - sum) / rows[i][i];
+    }
+    Some(x)
+}
+
+/// Polynomial with real coefficients.
+///
+/// Coefficients are stored from the lowest degree to the highest:
+/// `[c0, c1, c2]` represents `c0 + c1 x + c2 x^2`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, lowest degree first.
+    ///
+    /// # Example
+    /// ```
+    /// let p = Polynomial::new(vec![-2., 0., 1.]); // x^2 - 2
+    /// ```
+    pub fn new(coefficients: Vec<f64>) -> Self {
+        Polynomial { coefficients }
+    }
+
+    /// Gets the coefficients, lowest degree first.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's method.
+    ///
+    /// `c0 + c1 x + ... + cn x^n` is computed as `c0 + x (c1 + x (... + x cn))`,
+    /// with n multiplications and no powers.
+    ///
+    /// # Complexity
+    /// O(n) for a polynomial of degree n
+    ///
+    /// # Example
+    /// ```
+    /// let p = Polynomial::new(vec![1., 2., 3.]);
+    /// assert_eq!(p.evaluate(2.), 17.);
+    /// ```
+    ///
+    /// # Reference
+    /// [Horner's method - Wikipedia](https://en.wikipedia.org/wiki/Horner%27s_method)
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0., |accumulator, &coefficient| {
+                accumulator * x + coefficient
+            })
+    }
+
+    /// Computes the derivative polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// let p = Polynomial::new(vec![1., 2., 3.]);
+    /// assert_eq!(p.derivative(), Polynomial::new(vec![2., 6.]));
+    /// ```
+    pub fn derivative(&self) -> Polynomial {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(degree, &coefficient)| (degree as f64) * coefficient)
+            .collect();
+        Polynomial { coefficients }
+    }
+}
+
+/// Finds a root of `f` in [a, b] by bisection.
+///
+/// The interval is halved repeatedly, keeping the half where `f` changes sign,
+/// until its width is below `tolerance`.
+///
+/// # Arguments
+/// * `f` - The function (assumed continuous on [a, b])
+/// * `a`, `b` - Interval bounds
+/// * `tolerance` - Width of the final interval
+///
+/// # Returns
+/// `Some(x)` within `tolerance` of a root, or None if `f(a)` and `f(b)` have the same sign.
+///
+/// # Complexity
+/// O(log((b - a) / tolerance)) evaluations of `f` (linear convergence, but guaranteed)
+///
+/// # Example
+/// ```
+/// let sqrt_2 = find_root_bisection(|x| x * x - 2., 0., 2., 1e-12).unwrap();
+/// ```
+///
+/// # Reference
+/// [Bisection method - Wikipedia](https://en.wikipedia.org/wiki/Bisection_method)
+pub fn find_root_bisection(f: impl Fn(f64) -> f64, a: f64, b: f64, tolerance: f64) -> Option<f64> {
+    let (mut low, mut high) = if a <= b { (a, b) } else { (b, a) };
+    let mut f_low = f(low);
+    let f_high = f(high);
+    if f_low == 0. {
+        return Some(low);
+    }
+    if f_high == 0. {
+        return Some(high);
+    }
+    if f_low.signum() == f_high.signum() {
+        return None;
+    }
+
+    // The iteration cap stops the loop once the interval can no longer shrink in f64
+    let max_iter = 2000;
+    for _ in 0..max_iter {
+        if high - low <= tolerance {
+            break;
+        }
+        let mid = low + (high - low) / 2.;
+        let f_mid = f(mid);
+        if f_mid == 0. {
+            return Some(mid);
+        }
+        if f_mid.signum() == f_low.signum() {
+            low = mid;
+            f_low = f_mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Some(low + (high - low) / 2.)
+}
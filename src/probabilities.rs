@@ -2,16 +2,22 @@
 //!
 //! Implementation of various probabilistic algorithms and random number generators.
 //! Includes:
-//! - MINSTD linear congruential generator (Park-Miller, 1988)
-//! - Fisher-Yates shuffle algorithm
+//! - MINSTD linear congruential generator (Park-Miller, 1988), seeding from labels
+//! - Fisher-Yates shuffle algorithm (and a position distribution diagnostic)
 //! - Box-Muller transform for normal distribution
+//! - Monte Carlo estimation of Pi
 //! - Generic statistical functions (mean, variance)
+//! - Empirical cumulative distribution function
 //!
 //! WARNING: These generators are predictable and should NOT be used for cryptography or gambling.
 //!
 //! Author: Vincent Espitalier
 //! Date: June 2024
 
+#![allow(dead_code)]
+
+use crate::misc::mod_pow;
+
 /// Linear Congruential Generator (MINSTD/Park-Miller implementation)
 ///
 /// WARNING: This generator is highly predictable.
@@ -50,6 +56,66 @@ impl MinstdRng {
         }
     }
 
+    /// Returns the current internal state of the generator.
+    ///
+    /// Together with `set_state`, allows to checkpoint and resume a random sequence.
+    ///
+    /// # Example
+    /// ```
+    /// let mut rng = MinstdRng::new(42);
+    /// let checkpoint = rng.state();
+    /// ```
+    pub fn state(&self) -> u32 {
+        self.state
+    }
+
+    /// Restores the internal state of the generator (e.g. from a checkpoint).
+    ///
+    /// # Arguments
+    /// * `state` - New state value (must not be a multiple of the modulus 2^31 - 1)
+    ///
+    /// # Panics
+    /// Panics if state is a multiple of the modulus (such as 0): the generator would then stay at 0 forever.
+    ///
+    /// # Example
+    /// ```
+    /// let mut rng = MinstdRng::new(42);
+    /// let checkpoint = rng.state();
+    /// let first = rng.gen();
+    /// rng.set_state(checkpoint);
+    /// assert_eq!(rng.gen(), first);
+    /// ```
+    pub fn set_state(&mut self, state: u32) {
+        assert_ne!(
+            state as u64 % self.modulus,
+            0,
+            "State must not be a multiple of the modulus."
+        );
+        self.state = state;
+    }
+
+    /// Advances the generator as if `gen()` had been called `n` times.
+    ///
+    /// Since each step multiplies the state by the multiplier, the new state is
+    /// `state * multiplier^n mod modulus`, computed by modular exponentiation.
+    /// Useful to split a single sequence into independent parallel streams.
+    ///
+    /// # Arguments
+    /// * `n` - Number of steps to skip
+    ///
+    /// # Complexity
+    /// O(log n)
+    ///
+    /// # Example
+    /// ```
+    /// let mut rng = MinstdRng::new(42);
+    /// rng.jump(1_000_000);
+    /// ```
+    pub fn jump(&mut self, n: u64) {
+        let multiplier_pow_n: u64 = mod_pow(self.multiplier, n, self.modulus);
+        self.state = (((self.state as u64) * multiplier_pow_n) % self.modulus) as u32;
+    }
+
     /// Generates a new random number in the range [0, modulus-1].
     ///
     /// # Returns
@@ -66,6 +132,43 @@ impl MinstdRng {
         new_state
     }
 
+    /// Generates a uniformly distributed floating point number in [0, 1).
+    ///
+    /// # Returns
+    /// A pseudo-random f64 in [0, 1).
+    ///
+    /// # Example
+    /// ```
+    /// let mut rng = MinstdRng::new(42);
+    /// let u = rng.gen_f64();
+    /// assert!((0. ..1.).contains(&u));
+    /// ```
+    pub fn gen_f64(&mut self) -> f64 {
+        // gen() returns values in [1, modulus - 1]
+        ((self.gen() - 1) as f64) / ((self.modulus - 1) as f64)
+    }
+
+    /// Generates a random boolean, true with the given probability (Bernoulli trial).
+    ///
+    /// # Arguments
+    /// * `probability` - Probability of returning true, in [0, 1]
+    ///
+    /// # Panics
+    /// Panics if probability is not in [0, 1].
+    ///
+    /// # Example
+    /// ```
+    /// let mut rng = MinstdRng::new(42);
+    /// let coin_flip = rng.gen_bool(0.5);
+    /// ```
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        assert!(
+            (0. ..=1.).contains(&probability),
+            "Probability must be in [0, 1]."
+        );
+        self.gen_f64() < probability
+    }
+
     /// Generates a random number in the specified range.
     ///
     /// # Arguments
@@ -102,6 +205,43 @@ impl MinstdRng {
     }
 }
 
+/// Derives a reproducible `MinstdRng` seed from a label (e.g. a simulation run name).
+///
+/// The label is hashed with 32-bit FNV-1a, then reduced modulo the MINSTD modulus 2^31 - 1:
+/// a seed that is a multiple of the modulus would make the generator stuck at zero.
+/// A zero result is mapped to 1.
+///
+/// # Arguments
+/// * `label` - The label to hash
+///
+/// # Returns
+/// A seed in [1, 2^31 - 2], always the same for a given label.
+///
+/// # Example
+/// ```
+/// let mut rng = MinstdRng::new(seed_from_str("experiment-1"));
+/// ```
+///
+/// # Reference
+/// [Fowler-Noll-Vo hash function - Wikipedia](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+pub fn seed_from_str(label: &str) -> u32 {
+    // FNV-1a 32-bit constants
+    let offset_basis: u32 = 0x811C9DC5;
+    let prime: u32 = 0x01000193;
+
+    let mut hash: u32 = offset_basis;
+    for &byte in label.as_bytes() {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(prime);
+    }
+
+    let modulus: u32 = 0x7FFFFFFF; // 2^31 - 1, as in MinstdRng
+    match hash % modulus {
+        0 => 1,
+        seed => seed,
+    }
+}
+
 /// Performs Fisher-Yates shuffle on an array of integers.
 ///
 /// Implements an unbiased random permutation of array elements.
@@ -121,7 +261,25 @@ impl MinstdRng {
 pub fn fisher_yates_shuffle(array: &mut [i32], seed: u32) {
     // Use local MINSTD RNG implementation to avoid external dependencies
     let mut rng: MinstdRng = MinstdRng::new(seed);
+    fisher_yates_shuffle_with_rng(array, &mut rng);
+}
 
+/// Performs Fisher-Yates shuffle on an array of integers, drawing from an existing generator.
+///
+/// Successive calls on the same generator consume successive random numbers, so that
+/// repeated shuffles are independent (unlike reseeding for each shuffle).
+///
+/// # Arguments
+/// * `array` - The array to shuffle
+/// * `rng` - The random number generator
+///
+/// # Example
+/// ```
+/// let mut rng = MinstdRng::new(42);
+/// let mut arr = [1, 2, 3, 4, 5];
+/// fisher_yates_shuffle_with_rng(&mut arr, &mut rng);
+/// ```
+pub fn fisher_yates_shuffle_with_rng(array: &mut [i32], rng: &mut MinstdRng) {
     let n: usize = array.len();
     for i in (0..n).rev() {
         // Generate random index between 0 and i (inclusive)
@@ -132,6 +290,81 @@ pub fn fisher_yates_shuffle(array: &mut [i32], seed: u32) {
     }
 }
 
+/// Counts where each element lands over repeated Fisher-Yates shuffles, to diagnose bias.
+///
+/// The array `0..n` is shuffled `trials` times with `fisher_yates_shuffle_with_rng`, all
+/// trials drawing from a single MINSTD generator started at `seed`: each trial consumes
+/// its own random numbers, and none are reused between trials.
+///
+/// # Arguments
+/// * `n` - Number of elements to shuffle
+/// * `trials` - Number of shuffles
+/// * `seed` - Seed value for the random number generator
+///
+/// # Returns
+/// An n x n matrix, where `counts[element][position]` is the number of shuffles that put
+/// `element` at `position`. For an unbiased shuffle, every count is close to `trials / n`.
+///
+/// # Example
+/// ```
+/// let counts = shuffle_position_distribution(5, 10_000, 42); // ~ 2000 in each cell
+/// ```
+pub fn shuffle_position_distribution(n: usize, trials: usize, seed: u32) -> Vec<Vec<usize>> {
+    let mut counts: Vec<Vec<usize>> = vec![vec![0; n]; n];
+    let mut rng: MinstdRng = MinstdRng::new(seed);
+
+    for _ in 0..trials {
+        let mut array: Vec<i32> = (0..n as i32).collect();
+        fisher_yates_shuffle_with_rng(&mut array, &mut rng);
+
+        for (position, &element) in array.iter().enumerate() {
+            counts[element as usize][position] += 1;
+        }
+    }
+
+    counts
+}
+
+/// Samples `k` distinct elements uniformly, without replacement.
+///
+/// Implements a partial Fisher-Yates shuffle: only the first `k` positions of a copy
+/// of `items` are shuffled, then kept.
+///
+/// # Arguments
+/// * `items` - The elements to sample from
+/// * `k` - Number of elements to sample
+/// * `seed` - Seed value for the random number generator
+///
+/// # Returns
+/// A vector of `k` elements taken at distinct positions of `items`.
+///
+/// # Panics
+/// Panics if `k` is greater than the number of items.
+///
+/// # Example
+/// ```
+/// let sample = sample_without_replacement(&[1, 2, 3, 4, 5], 2, 42);
+/// assert_eq!(sample.len(), 2);
+/// ```
+pub fn sample_without_replacement<T: Clone>(items: &[T], k: usize, seed: u32) -> Vec<T> {
+    let n: usize = items.len();
+    assert!(
+        k <= n,
+        "Cannot sample more elements than there are items (k > n)."
+    );
+
+    let mut rng: MinstdRng = MinstdRng::new(seed);
+    let mut pool: Vec<T> = items.to_vec();
+    for i in 0..k {
+        // Generate random index between i and n - 1 (inclusive)
+        let j: usize = rng.gen_range(i..n) as usize;
+        pool.swap(i, j);
+    }
+
+    pool.truncate(k);
+    pool
+}
+
 /// Generates a pair of standard normal distributed random numbers using Box-Muller transform.
 ///
 /// WARNING: This generator is highly predictable.
@@ -241,6 +474,113 @@ where
     Some(T::from(sum / n_as_t))
 }
 
+/// Calculates the mean of an array of floats.
+///
+/// Non-generic counterpart of `mean`, with simpler bounds.
+///
+/// # Arguments
+/// * `array` - The array of values
+///
+/// # Returns
+/// An Option containing the mean value, or None if the array is empty.
+///
+/// # Example
+/// ```
+/// assert_eq!(mean_f64(&[1.0, 2.0, 3.0, 4.0]), Some(2.5));
+/// ```
+pub fn mean_f64(array: &[f64]) -> Option<f64> {
+    if array.is_empty() {
+        return None;
+    }
+    Some(array.iter().sum::<f64>() / array.len() as f64)
+}
+
+/// Calculates the mean of an array of integers, as a float.
+///
+/// The sum is computed exactly on i128, and the division is done in floating point,
+/// so the mean is not truncated (unlike `mean` on integer types).
+///
+/// # Arguments
+/// * `array` - The array of values
+///
+/// # Returns
+/// An Option containing the mean value, or None if the array is empty.
+///
+/// # Example
+/// ```
+/// assert_eq!(mean_int(&[1, 2]), Some(1.5));
+/// ```
+pub fn mean_int(array: &[i64]) -> Option<f64> {
+    if array.is_empty() {
+        return None;
+    }
+    let sum: i128 = array.iter().map(|&x| x as i128).sum();
+    Some(sum as f64 / array.len() as f64)
+}
+
+/// Calculates the geometric mean of an array of positive values.
+///
+/// Computed as `exp(mean(ln(x)))`, which equals the nth root of the product
+/// without overflowing on large products.
+///
+/// # Arguments
+/// * `array` - The array of values (must be positive)
+///
+/// # Returns
+/// An Option containing the geometric mean, or None if the array is empty.
+///
+/// # Panics
+/// Panics if a value is not positive.
+///
+/// # Example
+/// ```
+/// let geometric_mean = geometric_mean(&[1.0, 4.0]); // ~ 2.0
+/// ```
+///
+/// # Reference
+/// [Geometric mean - Wikipedia](https://en.wikipedia.org/wiki/Geometric_mean)
+pub fn geometric_mean(array: &[f64]) -> Option<f64> {
+    assert!(
+        array.iter().all(|&x| x > 0.),
+        "Error in geometric_mean: values must be positive."
+    );
+    let logs: Vec<f64> = array.iter().map(|x| x.ln()).collect();
+    mean_f64(&logs).map(f64::exp)
+}
+
+/// Calculates the harmonic mean of an array of positive values.
+///
+/// Like `geometric_mean`, only positive values are accepted: with mixed signs, the sum
+/// of inverses could be zero.
+///
+/// # Arguments
+/// * `array` - The array of values (must be positive)
+///
+/// # Returns
+/// An Option containing the harmonic mean `n / sum(1 / x)`, or None if the array is empty.
+///
+/// # Panics
+/// Panics if a value is not positive.
+///
+/// # Example
+/// ```
+/// assert_eq!(harmonic_mean(&[1.0, 4.0]), Some(1.6));
+/// ```
+///
+/// # Reference
+/// [Harmonic mean - Wikipedia](https://en.wikipedia.org/wiki/Harmonic_mean)
+pub fn harmonic_mean(array: &[f64]) -> Option<f64> {
+    assert!(
+        array.iter().all(|&x| x > 0.),
+        "Error in harmonic_mean: values must be positive."
+    );
+    if array.is_empty() {
+        return None;
+    }
+    let sum_of_inverses: f64 = array.iter().map(|x| 1. / x).sum();
+    Some(array.len() as f64 / sum_of_inverses)
+}
+
 /// Calculates the unbiased variance of an array of values.
 ///
 /// Generic implementation for any numeric type that supports
@@ -282,3 +622,193 @@ where
     let n_minus_delta_n_as_t = T::from((n - delta_n) as u32);
     Some(T::from(sum_of_squares / n_minus_delta_n_as_t))
 }
+
+/// Counts the values of an array falling into equal-width bins over [min, max).
+///
+/// Values below `min` are counted in the first bin, and values greater than or equal
+/// to `max` are counted in the last bin, so that every value is counted exactly once.
+///
+/// # Arguments
+/// * `data` - The values to bin
+/// * `bin_count` - Number of bins (must be non-zero)
+/// * `min`, `max` - Range covered by the bins (`min` < `max`)
+///
+/// # Returns
+/// A vector of `bin_count` counts.
+///
+/// # Panics
+/// Panics if `bin_count` is zero or if `min` >= `max`.
+///
+/// # Example
+/// ```
+/// assert_eq!(histogram(&[0.5, 1.5, 1.6], 2, 0.0, 2.0), vec![1, 2]);
+/// ```
+///
+/// # Reference
+/// [Histogram - Wikipedia](https://en.wikipedia.org/wiki/Histogram)
+pub fn histogram(data: &[f64], bin_count: usize, min: f64, max: f64) -> Vec<usize> {
+    assert!(
+        bin_count >= 1,
+        "Bin count must be greater than or equal to 1."
+    );
+    assert!(min < max, "Histogram range must satisfy min < max.");
+
+    let bin_width: f64 = (max - min) / (bin_count as f64);
+    let mut counts: Vec<usize> = vec![0; bin_count];
+
+    for &value in data {
+        let bin_index: usize = if value < min {
+            0
+        } else {
+            // Values at or above max fall into the last bin
+            std::cmp::min(((value - min) / bin_width) as usize, bin_count - 1)
+        };
+        counts[bin_index] += 1;
+    }
+
+    counts
+}
+
+/// Computes Pearson's chi-square goodness-of-fit statistic.
+///
+/// # Arguments
+/// * `observed` - Observed counts per category (e.g. returned by `histogram`)
+/// * `expected` - Expected counts per category under the tested distribution
+///
+/// # Returns
+/// `Some(Σ (O − E)² / E)`, or None if the lengths differ or if an expected count is zero.
+/// The statistic is 0 when observations match expectations exactly, and grows with the gap.
+///
+/// # Example
+/// ```
+/// assert_eq!(chi_square_statistic(&[10, 10], &[10., 10.]), Some(0.));
+/// ```
+///
+/// # Reference
+/// [Pearson's chi-squared test - Wikipedia](https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test)
+pub fn chi_square_statistic(observed: &[usize], expected: &[f64]) -> Option<f64> {
+    if observed.len() != expected.len() || expected.contains(&0.) {
+        return None;
+    }
+
+    let statistic = observed
+        .iter()
+        .zip(expected)
+        .map(|(&o, &e)| {
+            let delta = o as f64 - e;
+            delta * delta / e
+        })
+        .sum();
+    Some(statistic)
+}
+
+/// Estimates Pi with the Monte Carlo method.
+///
+/// Draws `samples` uniform points in the unit square [0, 1) x [0, 1), and counts those inside
+/// the quarter disk of radius 1, whose area is Pi / 4.
+///
+/// # Arguments
+/// * `samples` - Number of random points
+/// * `seed` - Seed value for the random number generator
+///
+/// # Returns
+/// `4 * inside / samples`.
+///
+/// # Panics
+/// Panics if `samples` is zero.
+///
+/// # Complexity
+/// O(samples), with a standard error of about 1.64 / sqrt(samples)
+///
+/// # Example
+/// ```
+/// let pi = estimate_pi_monte_carlo(1_000_000, 42); // ~ 3.14
+/// ```
+///
+/// # Reference
+/// [Monte Carlo method - Wikipedia](https://en.wikipedia.org/wiki/Monte_Carlo_method)
+pub fn estimate_pi_monte_carlo(samples: usize, seed: u32) -> f64 {
+    assert_ne!(
+        samples, 0,
+        "Error in estimate_pi_monte_carlo: samples must be non-zero."
+    );
+    let mut rng: MinstdRng = MinstdRng::new(seed);
+
+    let mut inside: usize = 0;
+    for _ in 0..samples {
+        let x: f64 = rng.gen_f64();
+        let y: f64 = rng.gen_f64();
+        if x * x + y * y < 1. {
+            inside += 1;
+        }
+    }
+
+    4. * (inside as f64) / (samples as f64)
+}
+
+/// Evaluates the empirical cumulative distribution function of a sample.
+///
+/// # Arguments
+/// * `data` - The sample
+/// * `x` - The point where to evaluate the function
+///
+/// # Returns
+/// The fraction of samples less than or equal to `x`, in [0, 1].
+///
+/// # Panics
+/// Panics if `data` is empty.
+///
+/// # Complexity
+/// O(n log n), to sort a copy of the sample
+///
+/// # Example
+/// ```
+/// assert_eq!(empirical_cdf(&[1., 2., 3., 4.], 2.5), 0.5);
+/// ```
+///
+/// # Reference
+/// [Empirical distribution function - Wikipedia](https://en.wikipedia.org/wiki/Empirical_distribution_function)
+pub fn empirical_cdf(data: &[f64], x: f64) -> f64 {
+    assert!(
+        !data.is_empty(),
+        "Error in empirical_cdf: data must not be empty."
+    );
+    let mut sorted: Vec<f64> = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let count_below: usize = sorted.partition_point(|&value| value <= x);
+    (count_below as f64) / (sorted.len() as f64)
+}
+
+/// Computes the steps of the empirical cumulative distribution function of a sample.
+///
+/// # Arguments
+/// * `data` - The sample
+///
+/// # Returns
+/// One point `(value, fraction of samples <= value)` per distinct value, by increasing value.
+/// The function is constant between consecutive points, and the last fraction is 1.
+/// Empty if `data` is empty.
+///
+/// # Complexity
+/// O(n log n)
+///
+/// # Example
+/// ```
+/// assert_eq!(empirical_cdf_curve(&[2., 1., 2.]), vec![(1., 1. / 3.), (2., 1.)]);
+/// ```
+pub fn empirical_cdf_curve(data: &[f64]) -> Vec<(f64, f64)> {
+    let mut sorted: Vec<f64> = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n: f64 = sorted.len() as f64;
+
+    let mut curve: Vec<(f64, f64)> = Vec::new();
+    for (i, &value) in sorted.iter().enumerate() {
+        // Only the last occurrence of a value gives its step height
+        if i + 1 < sorted.len() && sorted[i + 1] == value {
+            continue;
+        }
+        curve.push((value, ((i + 1) as f64) / n));
+    }
+    curve
+}
@@ -1,12 +1,14 @@
 //! Rational Numbers Implementation
 //!
 //! Generic implementation of rational numbers (fractions) with arithmetic operations.
-//! Supports addition, subtraction, multiplication, division, and comparison operations.
+//! Supports addition, subtraction, multiplication, division, and comparison operations,
+//! and the decimal expansion (with its repeating block) and continued fraction of `i64` fractions.
 //!
 //! Author: Vincent Espitalier
 //! Date: June 2024
 
 use std::cmp::{Ordering, PartialEq, PartialOrd};
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
@@ -78,6 +80,122 @@ where
     }
 }
 
+#[allow(dead_code)]
+impl<T> Rational<T>
+where
+    T: Clone,
+{
+    /// Gets the numerator (carries the sign of the fraction).
+    pub fn numerator(&self) -> T {
+        self.numerator.clone()
+    }
+
+    /// Gets the denominator (always positive).
+    pub fn denominator(&self) -> T {
+        self.denominator.clone()
+    }
+}
+
+#[allow(dead_code)]
+impl Rational<i64> {
+    /// Computes the decimal expansion of the fraction, detecting its repeating block.
+    ///
+    /// Digits are produced by long division. The remainders are tracked: as soon as one
+    /// repeats, the digits produced since its first occurrence form the repeating block.
+    ///
+    /// # Arguments
+    /// * `max_digits` - Maximum number of fractional digits to compute.
+    ///
+    /// # Returns
+    /// The non-repeating part (sign, integer part and first fractional digits) and the
+    /// repeating block, if any. If the expansion neither terminates nor repeats within
+    /// `max_digits` digits, the non-repeating part holds the first `max_digits` digits
+    /// (truncated expansion) and no repeating block is returned.
+    ///
+    /// # Example
+    /// ```
+    /// let third = Rational::new(1i64, 3i64);
+    /// assert_eq!(third.decimal_expansion(10), ("0.".to_string(), Some("3".to_string())));
+    /// let quarter = Rational::new(1i64, 4i64);
+    /// assert_eq!(quarter.decimal_expansion(10), ("0.25".to_string(), None));
+    /// ```
+    ///
+    /// # Reference
+    /// [Repeating decimal - Wikipedia](https://en.wikipedia.org/wiki/Repeating_decimal)
+    pub fn decimal_expansion(&self, max_digits: usize) -> (String, Option<String>) {
+        // i128 avoids overflows on abs(i64::MIN) and on remainder * 10
+        let numerator = (self.numerator as i128).abs();
+        let denominator = self.denominator as i128;
+
+        let sign = if self.numerator < 0 { "-" } else { "" };
+        let mut prefix = format!("{}{}", sign, numerator / denominator);
+        let mut remainder = numerator % denominator;
+        if remainder == 0 {
+            return (prefix, None);
+        }
+        prefix.push('.');
+
+        // Position in `digits` of the first digit computed from each remainder
+        let mut remainder_positions: HashMap<i128, usize> = HashMap::new();
+        let mut digits = String::new();
+        loop {
+            if remainder == 0 {
+                return (prefix + &digits, None);
+            }
+            if let Some(&start) = remainder_positions.get(&remainder) {
+                let repeating_block = digits[start..].to_string();
+                digits.truncate(start);
+                return (prefix + &digits, Some(repeating_block));
+            }
+            if digits.len() == max_digits {
+                return (prefix + &digits, None);
+            }
+
+            remainder_positions.insert(remainder, digits.len());
+            remainder *= 10;
+            digits.push(char::from(b'0' + (remainder / denominator) as u8));
+            remainder %= denominator;
+        }
+    }
+
+    /// Computes the continued fraction coefficients `[a0; a1, a2, ...]` of the fraction.
+    ///
+    /// The coefficients are the successive quotients of the Euclidean algorithm on the
+    /// numerator and the denominator, so that `x = a0 + 1 / (a1 + 1 / (a2 + ...))`.
+    /// `a0` is the floor of the fraction (negative for negative fractions), and the
+    /// following coefficients are positive.
+    ///
+    /// # Returns
+    /// The coefficients (a single one for integers).
+    ///
+    /// # Complexity
+    /// O(log(denominator)) coefficients
+    ///
+    /// # Example
+    /// ```
+    /// let r = Rational::new(43i64, 30i64);
+    /// assert_eq!(r.continued_fraction(), vec![1, 2, 3, 4]);
+    /// ```
+    ///
+    /// # Reference
+    /// [Continued fraction - Wikipedia](https://en.wikipedia.org/wiki/Continued_fraction)
+    pub fn continued_fraction(&self) -> Vec<i64> {
+        let mut coefficients: Vec<i64> = Vec::new();
+        let mut numerator = self.numerator;
+        let mut denominator = self.denominator;
+
+        // Euclidean algorithm, with floored quotients (the denominator stays positive)
+        while denominator != 0 {
+            coefficients.push(numerator.div_euclid(denominator));
+            let remainder = numerator.rem_euclid(denominator);
+            numerator = denominator;
+            denominator = remainder;
+        }
+
+        coefficients
+    }
+}
+
 // Trait Add: c = a + b
 impl<T> Add for Rational<T>
 where
@@ -5,8 +5,9 @@
 //! - Insertion sort
 //! - Selection sort
 //! - Quick sort
-//! - Merge sort
+//! - Merge sort (and merge of two sorted slices, inversion count)
 //! - Heap sort (generic implementation)
+//! - Sortedness checks (ascending, sort direction)
 //!
 //! For generic implementations, see algos_tri_variantes.rs.
 //!
@@ -33,8 +34,6 @@
 /// # Reference
 /// [Insertion sort - Wikipedia](https://en.wikipedia.org/wiki/Insertion_sort)
 pub fn insertion_sort(array: &mut [i32]) {
-    println!("insertion_sort > called");
-
     let n = array.len();
 
     // Sort elements of the array successively
@@ -80,8 +79,6 @@ pub fn insertion_sort(array: &mut [i32]) {
 /// # Reference
 /// [Selection sort - Wikipedia](https://en.wikipedia.org/wiki/Selection_sort)
 pub fn selection_sort(array: &mut [i32]) {
-    println!("selection_sort > called");
-
     let n = array.len();
 
     // Special case: array with only one element
@@ -181,6 +178,53 @@ pub fn quick_sort(array: &mut [i32]) {
     }
 }
 
+/// Merges two sorted slices into a single sorted vector.
+///
+/// On ties, the element of `a` comes first, so that the merge is stable.
+///
+/// # Type Parameters
+/// * `T` - Type of elements (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `a` - First sorted slice
+/// * `b` - Second sorted slice
+///
+/// # Returns
+/// Vector with all elements of `a` and `b`, in sorted order
+///
+/// # Complexity
+/// O(n + m) time complexity
+///
+/// # Example
+/// ```
+/// assert_eq!(merge_sorted(&[1, 3, 5], &[2, 4, 6]), vec![1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn merge_sorted<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Ord + Clone,
+{
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let mut a_index = 0;
+    let mut b_index = 0;
+
+    // Take the smaller element at each step, preferring `a` on ties for stability
+    while a_index < a.len() && b_index < b.len() {
+        if a[a_index] <= b[b_index] {
+            merged.push(a[a_index].clone());
+            a_index += 1;
+        } else {
+            merged.push(b[b_index].clone());
+            b_index += 1;
+        }
+    }
+
+    // At most one of the slices still has elements
+    merged.extend_from_slice(&a[a_index..]);
+    merged.extend_from_slice(&b[b_index..]);
+
+    merged
+}
+
 /// Sorts an array using the merge sort algorithm.
 ///
 /// # Arguments
@@ -229,43 +273,78 @@ pub fn merge_sort(array: &mut [i32]) {
     merge_sort(right_array);
 
     // Merge the sorted sub-arrays
-    let mut merged_array: Vec<i32> = Vec::new();
+    let merged_array = merge_sorted(left_array, right_array);
+
+    // Copy the merged array back to the original array
+    array.clone_from_slice(&merged_array);
+}
+
+/// Sorts an array with merge sort, and returns its number of inversions.
+fn merge_sort_count_inversions<T>(array: &mut [T]) -> u64
+where
+    T: Ord + Clone,
+{
+    let n = array.len();
+    if n <= 1 {
+        return 0;
+    }
+
+    let mid = n / 2;
+    let (left_array, right_array) = array.split_at_mut(mid);
+    let mut inversions =
+        merge_sort_count_inversions(left_array) + merge_sort_count_inversions(right_array);
+
+    // Each right element is inverted with all the left elements strictly greater than it.
+    // Both halves are sorted, so these are the left elements after the first greater one.
     let mut left_index = 0;
-    let mut right_index = 0;
-
-    // Merge the two arrays by taking the smaller element at each step
-    for _ in 0..n {
-        if left_index < left_array.len() && right_index < right_array.len() {
-            // Both sub-arrays still have elements to process
-            if left_array[left_index] <= right_array[right_index] {
-                merged_array.push(left_array[left_index]);
-                left_index += 1;
-            } else {
-                merged_array.push(right_array[right_index]);
-                right_index += 1;
-            }
-        } else if left_index < left_array.len() {
-            // Right array has been fully processed
-            merged_array.push(left_array[left_index]);
+    for elem in right_array.iter() {
+        while left_index < mid && left_array[left_index] <= *elem {
             left_index += 1;
-        } else if right_index < right_array.len() {
-            // Left array has been fully processed
-            merged_array.push(right_array[right_index]);
-            right_index += 1;
-        } else {
-            // This case should never happen
-            panic!("Internal error: Main array not filled, but both sub-arrays have been fully processed.");
         }
+        inversions += (mid - left_index) as u64;
     }
 
-    // Copy the merged array back to the original array
+    let merged_array = merge_sorted(left_array, right_array);
     array.clone_from_slice(&merged_array);
+    inversions
+}
+
+/// Counts the inversions of an array, i.e. the pairs (i, j) with i < j and array[i] > array[j].
+///
+/// The count measures how far the array is from being sorted: 0 for a sorted array,
+/// n(n-1)/2 for a strictly decreasing one.
+///
+/// # Type Parameters
+/// * `T` - Type of elements (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `array` - Slice of elements (left unchanged)
+///
+/// # Returns
+/// Number of inversions
+///
+/// # Complexity
+/// O(n log n) time, O(n) extra space: inversions are counted during a merge sort of a copy
+///
+/// # Example
+/// ```
+/// assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+/// ```
+///
+/// # Reference
+/// [Inversion (discrete mathematics) - Wikipedia](https://en.wikipedia.org/wiki/Inversion_(discrete_mathematics))
+pub fn count_inversions<T>(array: &[T]) -> u64
+where
+    T: Ord + Clone,
+{
+    let mut copy = array.to_vec();
+    merge_sort_count_inversions(&mut copy)
 }
 
 /// Sorts an array using the heap sort algorithm (generic implementation).
 ///
 /// # Type Parameters
-/// * `T` - Type of elements to sort (must be Ord + Clone + Debug)
+/// * `T` - Type of elements to sort (must be Ord + Clone)
 ///
 /// # Arguments
 /// * `array` - Mutable slice of elements to be sorted
@@ -288,9 +367,8 @@ pub fn merge_sort(array: &mut [i32]) {
 /// [Heap sort - Wikipedia](https://en.wikipedia.org/wiki/Heapsort)
 pub fn heap_sort<T>(array: &mut [T])
 where
-    T: Ord + Clone + core::fmt::Debug,
+    T: Ord + Clone,
 {
-    println!("heap_sort > called");
     let n = array.len();
 
     // Base case: array with 0 or 1 element (nothing to sort)
@@ -310,8 +388,8 @@ where
     // Extract elements from the heap in descending order
     for i in (0..n).rev() {
         let element = heap.pop();
-        assert_ne!(
-            element, None,
+        assert!(
+            element.is_some(),
             "heap_sort: Internal error (1): There should be elements left in the heap."
         );
 
@@ -324,9 +402,8 @@ where
         // - The heap contains the remaining elements, with the root pointing to the largest element
     }
 
-    assert_eq!(
-        heap.pop(),
-        None,
+    assert!(
+        heap.pop().is_none(),
         "heap_sort: Internal error (2): There should be no elements left in the heap."
     );
 }
@@ -359,3 +436,60 @@ where
     }
     true
 }
+
+/// Order of an array, as classified by `sort_order`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Non-decreasing, with at least one strict increase
+    Ascending,
+    /// Non-increasing, with at least one strict decrease
+    Descending,
+    /// All elements equal (including empty and single-element arrays)
+    Constant,
+    /// Both a strict increase and a strict decrease occur
+    Unsorted,
+}
+
+/// Classifies the order of an array in a single pass.
+///
+/// # Type Parameters
+/// * `T` - Type of elements in the array (must be Ord)
+///
+/// # Arguments
+/// * `array` - Slice of elements to check
+///
+/// # Returns
+/// The `SortOrder` of the array. Equal neighbours do not affect the direction, so
+/// `[1, 1, 2]` is `Ascending`.
+///
+/// # Complexity
+/// O(n), stops at the first pair contradicting an earlier direction
+///
+/// # Example
+/// ```
+/// assert_eq!(sort_order(&[3, 2, 2, 1]), SortOrder::Descending);
+/// assert_eq!(sort_order(&[1, 3, 2]), SortOrder::Unsorted);
+/// ```
+pub fn sort_order<T>(array: &[T]) -> SortOrder
+where
+    T: Ord,
+{
+    let mut increases = false;
+    let mut decreases = false;
+    for pair in array.windows(2) {
+        match pair[0].cmp(&pair[1]) {
+            std::cmp::Ordering::Less => increases = true,
+            std::cmp::Ordering::Greater => decreases = true,
+            std::cmp::Ordering::Equal => {}
+        }
+        if increases && decreases {
+            return SortOrder::Unsorted;
+        }
+    }
+
+    match (increases, decreases) {
+        (true, _) => SortOrder::Ascending,
+        (_, true) => SortOrder::Descending,
+        _ => SortOrder::Constant,
+    }
+}
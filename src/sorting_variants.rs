@@ -2,15 +2,26 @@
 //!
 //! This module contains alternative implementations of sorting algorithms:
 //! - Generic implementations using Rust traits
-//! - Indirect sorting (returns permutation indices instead of sorted data)
+//! - Indirect sorting (returns permutation indices instead of sorted data), permutation parity
 //! - Optimized implementations with reduced memory allocations
 //! - Modified versions of sorting algorithms from literature
+//! - K-way merge of sorted slices
+//! - Streaming top-k selection with a bounded heap
+//! - Sorting networks for small fixed sizes
+//! - Timsort (natural merge sort with galloping)
+//! - Stooge sort (correct but impractical, for teaching)
+//! - Single entry point choosing the algorithm, the direction and the stability
 //!
 //! Author: Vincent Espitalier
 //! Date: June 2024
 
 #![allow(dead_code)]
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::sorting::heap_sort;
+
 /// Generic implementation of insertion sort.
 ///
 /// Sorts any array whose elements implement PartialOrd and Clone traits.
@@ -32,8 +43,6 @@ pub fn generic_insertion_sort<T>(array: &mut [T])
 where
     T: PartialOrd + Clone,
 {
-    println!("generic_insertion_sort > called");
-
     let n = array.len();
 
     // Sort elements of the array successively
@@ -107,8 +116,6 @@ pub fn generic_indirect_selection_sort<T>(array: &[T]) -> Vec<usize>
 where
     T: Ord,
 {
-    println!("generic_indirect_selection_sort > called");
-
     let n = array.len();
     let mut permutation: Vec<usize> = (0..n).collect();
 
@@ -156,6 +163,121 @@ where
     sorted_array
 }
 
+/// Applies a permutation to an array in place, following its cycles.
+///
+/// Produces the same result as `permute_copy_array` (`array[i]` becomes the former
+/// `array[permutation[i]]`) without allocating a new array. Processed entries of
+/// `permutation` are temporarily marked by adding n to them, and the permutation
+/// is restored before returning.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to permute
+///
+/// # Arguments
+/// * `array` - Array to reorder
+/// * `permutation` - Permutation of `0..n` (restored on return)
+///
+/// # Panics
+/// Panics if the lengths differ. The permutation is assumed to be valid.
+///
+/// # Complexity
+/// O(n) time, O(1) extra memory
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// let mut permutation = vec![4, 1, 5, 2, 0, 3];
+/// apply_permutation_in_place(&mut arr, &mut permutation);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn apply_permutation_in_place<T>(array: &mut [T], permutation: &mut [usize]) {
+    let n = array.len();
+    assert_eq!(
+        n,
+        permutation.len(),
+        "Error in apply_permutation_in_place: array and permutation lengths differ."
+    );
+
+    for start in 0..n {
+        if permutation[start] >= n {
+            // Already processed as part of a previous cycle
+            continue;
+        }
+
+        // Along the cycle, array[start] is carried forward by successive swaps
+        let mut current = start;
+        loop {
+            let next = permutation[current];
+            permutation[current] += n;
+            if next == start {
+                break;
+            }
+            array.swap(current, next);
+            current = next;
+        }
+    }
+
+    // Restore the permutation
+    for index in permutation.iter_mut() {
+        *index -= n;
+    }
+}
+
+/// Computes the parity of a permutation of `0..n`.
+///
+/// A permutation made of c cycles (fixed points included) is a product of n - c
+/// transpositions, so its parity is that of n - c.
+///
+/// # Arguments
+/// * `permutation` - Permutation of `0..n`, e.g. returned by `generic_indirect_selection_sort`
+///
+/// # Returns
+/// `true` if the permutation is even, `false` if it is odd
+///
+/// # Panics
+/// Panics if `permutation` is not a permutation of `0..n`.
+///
+/// # Complexity
+/// O(n) time, O(n) memory
+///
+/// # Example
+/// ```
+/// assert!(permutation_parity(&[0, 1, 2]));
+/// assert!(!permutation_parity(&[1, 0, 2]));
+/// ```
+///
+/// # Reference
+/// [Parity of a permutation - Wikipedia](https://en.wikipedia.org/wiki/Parity_of_a_permutation)
+pub fn permutation_parity(permutation: &[usize]) -> bool {
+    let n = permutation.len();
+
+    let mut seen = vec![false; n];
+    for &index in permutation {
+        assert!(
+            index < n && !seen[index],
+            "Error in permutation_parity: input is not a permutation of 0..n."
+        );
+        seen[index] = true;
+    }
+
+    // Count the cycles
+    let mut visited = vec![false; n];
+    let mut cycle_count = 0;
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        cycle_count += 1;
+        let mut index = start;
+        while !visited[index] {
+            visited[index] = true;
+            index = permutation[index];
+        }
+    }
+
+    (n - cycle_count).is_multiple_of(2)
+}
+
 /// Optimized merge sort implementation with reduced memory allocations.
 ///
 /// Uses only one additional allocation (half the size of the original array)
@@ -179,7 +301,6 @@ pub fn optimized_merge_sort(
     let missing_args = min_index.is_none() || max_index.is_none() || temp_array.is_none();
 
     if missing_args {
-        println!("Calling optimized_merge_sort");
         // Handle the initial user call (non-recursive)
         let array_len = array.len();
         let temp_array_len = array_len / 2 + 1;
@@ -258,3 +379,1124 @@ pub fn optimized_merge_sort(
         }
     }
 }
+
+/// Tests whether an array contains at least two equal elements.
+///
+/// # Type Parameters
+/// * `T` - Type of elements (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `array` - Array of elements to check
+///
+/// # Returns
+/// `true` if some value appears more than once
+///
+/// # Complexity
+/// O(n log n): a copy of the array is sorted, then adjacent pairs are compared.
+/// Unlike a hash set based approach, this does not require `Hash`.
+///
+/// # Example
+/// ```
+/// assert!(has_duplicates(&[3, 1, 2, 3]));
+/// ```
+pub fn has_duplicates<T>(array: &[T]) -> bool
+where
+    T: Ord + Clone,
+{
+    let mut sorted_array: Vec<T> = array.to_vec();
+    sorted_array.sort();
+    sorted_array.windows(2).any(|pair| pair[0] == pair[1])
+}
+
+/// Finds the values appearing more than once in an array.
+///
+/// # Type Parameters
+/// * `T` - Type of elements (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `array` - Array of elements to check
+///
+/// # Returns
+/// The duplicated values, each reported once, in ascending order
+///
+/// # Complexity
+/// O(n log n)
+///
+/// # Example
+/// ```
+/// assert_eq!(find_duplicates(&[3, 1, 2, 3, 1]), vec![1, 3]);
+/// ```
+pub fn find_duplicates<T>(array: &[T]) -> Vec<T>
+where
+    T: Ord + Clone,
+{
+    let mut sorted_array: Vec<T> = array.to_vec();
+    sorted_array.sort();
+
+    let mut duplicates: Vec<T> = Vec::new();
+    for pair in sorted_array.windows(2) {
+        // Equal neighbors: report the value once, whatever its number of occurrences
+        if pair[0] == pair[1] && duplicates.last() != Some(&pair[0]) {
+            duplicates.push(pair[0].clone());
+        }
+    }
+    duplicates
+}
+
+/// Quickselect with a custom comparison function (see `quickselect`).
+fn quickselect_by<T, F>(array: &[T], k: usize, compare: F) -> Option<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> std::cmp::Ordering,
+{
+    use std::cmp::Ordering;
+
+    let n = array.len();
+    if k >= n {
+        return None;
+    }
+
+    let mut data: Vec<T> = array.to_vec();
+    let mut min_index = 0;
+    let mut max_index = n - 1;
+
+    loop {
+        if min_index == max_index {
+            return Some(data[min_index].clone());
+        }
+
+        // Median-of-three pivot: sort the first, middle and last elements
+        let mid_index = min_index + (max_index - min_index) / 2;
+        if compare(&data[mid_index], &data[min_index]) == Ordering::Less {
+            data.swap(mid_index, min_index);
+        }
+        if compare(&data[max_index], &data[min_index]) == Ordering::Less {
+            data.swap(max_index, min_index);
+        }
+        if compare(&data[max_index], &data[mid_index]) == Ordering::Less {
+            data.swap(max_index, mid_index);
+        }
+        let pivot: T = data[mid_index].clone();
+
+        // Three-way partition: [min, lower) < pivot, [lower, upper] == pivot, (upper, max] > pivot
+        let mut lower = min_index;
+        let mut upper = max_index;
+        let mut i = min_index;
+        while i <= upper {
+            match compare(&data[i], &pivot) {
+                Ordering::Less => {
+                    data.swap(i, lower);
+                    lower += 1;
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    data.swap(i, upper);
+                    upper -= 1;
+                }
+                Ordering::Equal => i += 1,
+            }
+        }
+
+        // Continue in the part containing the kth element
+        if k < lower {
+            max_index = lower - 1;
+        } else if k > upper {
+            min_index = upper + 1;
+        } else {
+            return Some(pivot);
+        }
+    }
+}
+
+/// Finds the kth smallest element of an array (0-indexed) using quickselect.
+///
+/// Hoare's selection algorithm: the array is partitioned around a median-of-three pivot,
+/// and the search continues only in the part containing the kth element.
+/// The input array is not modified (the algorithm works on a copy).
+///
+/// # Type Parameters
+/// * `T` - Type of elements (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `array` - Array of elements
+/// * `k` - Rank of the element to find (0 for the minimum)
+///
+/// # Returns
+/// `Some(element)` of rank `k` in sorted order, `None` if `k >= array.len()`
+///
+/// # Complexity
+/// O(n) expected time, O(n²) worst case
+///
+/// # Example
+/// ```
+/// assert_eq!(quickselect(&[5, 2, 4, 6, 1, 3], 2), Some(3));
+/// ```
+///
+/// # Reference
+/// [Quickselect - Wikipedia](https://en.wikipedia.org/wiki/Quickselect)
+pub fn quickselect<T>(array: &[T], k: usize) -> Option<T>
+where
+    T: Ord + Clone,
+{
+    quickselect_by(array, k, |a, b| a.cmp(b))
+}
+
+/// Generic in-place selection sort.
+///
+/// At each pass, the minimum of the unsorted part is swapped into place.
+/// Unlike `generic_insertion_sort`, elements are only swapped, so no Clone is required.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Complexity
+/// O(n²) comparisons, at most n - 1 swaps
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// generic_selection_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn generic_selection_sort<T>(array: &mut [T])
+where
+    T: Ord,
+{
+    let n = array.len();
+
+    for i in 0..n {
+        // Find the minimum of the unsorted part array[i..n]
+        let mut min_index = i;
+        for j in (i + 1)..n {
+            if array[j] < array[min_index] {
+                min_index = j;
+            }
+        }
+        array.swap(i, min_index);
+
+        // Loop invariant:
+        // After each iteration, the first (i+1) elements are sorted and in their final position
+    }
+}
+
+/// Generic bubble sort with early exit.
+///
+/// Adjacent elements are swapped while out of order; after each pass, the largest
+/// remaining element has bubbled up to its final position. The sort stops as soon as
+/// a pass makes no swap, so an already sorted array is handled in a single pass.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Complexity
+/// O(n²) worst case, O(n) on sorted input
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// bubble_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// # Reference
+/// [Bubble sort - Wikipedia](https://en.wikipedia.org/wiki/Bubble_sort)
+pub fn bubble_sort<T>(array: &mut [T])
+where
+    T: Ord,
+{
+    let mut end = array.len();
+
+    while end > 1 {
+        let mut swapped = false;
+        for i in 1..end {
+            if array[i - 1] > array[i] {
+                array.swap(i - 1, i);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+
+        // Loop invariant:
+        // array[end - 1] now holds the largest element of array[0..end]
+        end -= 1;
+    }
+}
+
+/// Generic cocktail shaker sort (bidirectional bubble sort).
+///
+/// Alternates forward passes, which move the largest element to the end of the active range,
+/// and backward passes, which move the smallest element to its beginning. Small elements
+/// near the end ("turtles") thus reach their position in one backward pass, instead of moving
+/// by one position per pass as in `bubble_sort`. The sort stops as soon as a pass makes no swap.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Complexity
+/// O(n²) worst case, O(n) on sorted input
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// cocktail_shaker_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// # Reference
+/// [Cocktail shaker sort - Wikipedia](https://en.wikipedia.org/wiki/Cocktail_shaker_sort)
+pub fn cocktail_shaker_sort<T>(array: &mut [T])
+where
+    T: Ord,
+{
+    // Active range is array[start..end]
+    let mut start = 0;
+    let mut end = array.len();
+
+    while end - start > 1 {
+        // Forward pass: bubble up the largest element
+        let mut swapped = false;
+        for i in (start + 1)..end {
+            if array[i - 1] > array[i] {
+                array.swap(i - 1, i);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+        end -= 1;
+
+        // Backward pass: bubble down the smallest element
+        swapped = false;
+        for i in ((start + 1)..end).rev() {
+            if array[i - 1] > array[i] {
+                array.swap(i - 1, i);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+        start += 1;
+    }
+}
+
+/// Computes the next gap of comb sort, by shrinking the current gap by a factor of 1.3.
+///
+/// # Arguments
+/// * `gap` - Current gap
+///
+/// # Returns
+/// The shrunk gap, never less than 1
+///
+/// # Example
+/// ```
+/// assert_eq!(comb_sort_next_gap(13), 10);
+/// assert_eq!(comb_sort_next_gap(1), 1);
+/// ```
+pub fn comb_sort_next_gap(gap: usize) -> usize {
+    // Integer form of gap / 1.3
+    (gap * 10 / 13).max(1)
+}
+
+/// Generic comb sort.
+///
+/// Variant of bubble sort comparing elements `gap` positions apart, where the gap starts
+/// at the array length and shrinks by a factor of 1.3 at each pass. Large elements at the
+/// beginning and small elements at the end thus move over long distances early.
+/// Once the gap reaches 1, passes are bubble sort passes, repeated until no swap occurs.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Complexity
+/// O(n²) worst case, O(n log n) in practice on random inputs
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// comb_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// # Reference
+/// [Comb sort - Wikipedia](https://en.wikipedia.org/wiki/Comb_sort)
+pub fn comb_sort<T>(array: &mut [T])
+where
+    T: Ord,
+{
+    let n = array.len();
+    let mut gap = n;
+    let mut sorted = false;
+
+    while !sorted {
+        gap = comb_sort_next_gap(gap);
+        // The array can only be sorted after a swap-free pass with gap 1
+        sorted = gap == 1;
+
+        for i in gap..n {
+            if array[i - gap] > array[i] {
+                array.swap(i - gap, i);
+                sorted = false;
+            }
+        }
+    }
+}
+
+/// Merges any number of sorted slices into a single sorted vector.
+///
+/// A min-heap holds the current head `(value, slice_index, element_index)` of each
+/// non-exhausted slice. At each step the smallest head is output and replaced with the
+/// next element of its slice. On ties, the element of the slice with the lower index
+/// comes first, so the merge is stable.
+///
+/// # Type Parameters
+/// * `T` - Type of elements (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `slices` - Sorted slices to merge
+///
+/// # Returns
+/// Vector with all elements of the slices, in sorted order
+///
+/// # Complexity
+/// O(n log k) time, where n is the total number of elements and k the number of slices
+///
+/// # Example
+/// ```
+/// let merged = k_way_merge(&[&[1, 4], &[2, 5], &[3, 6]]);
+/// assert_eq!(merged, vec![1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// # See also
+/// `sorting::merge_sorted` for two slices
+pub fn k_way_merge<T>(slices: &[&[T]]) -> Vec<T>
+where
+    T: Ord + Clone,
+{
+    let total_len = slices.iter().map(|slice| slice.len()).sum();
+    let mut merged = Vec::with_capacity(total_len);
+
+    // BinaryHeap is a max-heap: Reverse turns it into a min-heap
+    let mut heap = BinaryHeap::with_capacity(slices.len());
+    for (slice_index, slice) in slices.iter().enumerate() {
+        if let Some(first) = slice.first() {
+            heap.push(Reverse((first.clone(), slice_index, 0)));
+        }
+    }
+
+    while let Some(Reverse((value, slice_index, element_index))) = heap.pop() {
+        merged.push(value);
+        if let Some(next) = slices[slice_index].get(element_index + 1) {
+            heap.push(Reverse((next.clone(), slice_index, element_index + 1)));
+        }
+    }
+
+    merged
+}
+
+/// Generic cycle sort, minimizing the number of writes.
+///
+/// For each position, the element there is moved directly to its final position (the number
+/// of smaller elements), and the element it displaces is moved in turn, until the cycle
+/// of the permutation closes. Each element is thus written at most once.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Returns
+/// The number of writes into the array, which is at most n, and 0 on a sorted input
+///
+/// # Complexity
+/// O(n²) comparisons, minimal number of writes
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// let writes = cycle_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+/// assert!(writes <= 6);
+/// ```
+///
+/// # Reference
+/// [Cycle sort - Wikipedia](https://en.wikipedia.org/wiki/Cycle_sort)
+pub fn cycle_sort<T>(array: &mut [T]) -> usize
+where
+    T: Ord + Clone,
+{
+    let n = array.len();
+    let mut writes = 0;
+
+    for cycle_start in 0..n {
+        let mut item = array[cycle_start].clone();
+
+        // Final position of item: cycle_start + number of smaller elements after it
+        let mut position = cycle_start
+            + array[(cycle_start + 1)..]
+                .iter()
+                .filter(|&x| *x < item)
+                .count();
+        if position == cycle_start {
+            // Already in place
+            continue;
+        }
+
+        // Skip duplicates already placed
+        while array[position] == item {
+            position += 1;
+        }
+        std::mem::swap(&mut array[position], &mut item);
+        writes += 1;
+
+        // Rotate the rest of the cycle
+        while position != cycle_start {
+            position = cycle_start
+                + array[(cycle_start + 1)..]
+                    .iter()
+                    .filter(|&x| *x < item)
+                    .count();
+            while position != cycle_start && array[position] == item {
+                position += 1;
+            }
+            std::mem::swap(&mut array[position], &mut item);
+            writes += 1;
+        }
+    }
+
+    writes
+}
+
+/// Generic pancake sort, using prefix reversals only.
+///
+/// At each step, the largest element of the unsorted prefix is flipped to the front,
+/// then the whole unsorted prefix is flipped to move it to its final position.
+/// Flips that would do nothing are skipped.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Returns
+/// The sizes of the flips performed, in order: a flip of size k reverses `array[..k]`.
+/// At most 2(n - 1) flips are performed.
+///
+/// # Complexity
+/// O(n²) comparisons and element moves
+///
+/// # Example
+/// ```
+/// let mut arr = [3, 1, 2];
+/// let flips = pancake_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// assert_eq!(flips, vec![3, 2]);
+/// ```
+///
+/// # Reference
+/// [Pancake sorting - Wikipedia](https://en.wikipedia.org/wiki/Pancake_sorting)
+pub fn pancake_sort<T>(array: &mut [T]) -> Vec<usize>
+where
+    T: Ord,
+{
+    let mut flips = Vec::new();
+
+    for size in (2..=array.len()).rev() {
+        // Position of the (last) maximum of the unsorted prefix
+        let mut max_index = 0;
+        for i in 1..size {
+            if array[i] >= array[max_index] {
+                max_index = i;
+            }
+        }
+        if max_index == size - 1 {
+            continue;
+        }
+
+        // Bring the maximum to the front, then to the end of the prefix
+        if max_index > 0 {
+            array[..=max_index].reverse();
+            flips.push(max_index + 1);
+        }
+        array[..size].reverse();
+        flips.push(size);
+    }
+
+    flips
+}
+
+/// Computes the median of an array of floats using quickselect, without sorting.
+///
+/// For an odd length, the median is the middle order statistic; for an even length,
+/// it is the mean of the two middle ones, each found by a quickselect.
+/// Floats are compared with `f64::total_cmp`, so NaN values are ordered after
+/// (positive NaN) or before (negative NaN) all numbers.
+///
+/// # Arguments
+/// * `array` - Array of values
+///
+/// # Returns
+/// `Some(median)`, or `None` if the array is empty
+///
+/// # Complexity
+/// O(n) expected time, instead of O(n log n) for a sort-based median
+///
+/// # Example
+/// ```
+/// assert_eq!(median_fast(&[3., 1., 2.]), Some(2.));
+/// assert_eq!(median_fast(&[4., 1., 3., 2.]), Some(2.5));
+/// ```
+///
+/// # Reference
+/// [Median - Wikipedia](https://en.wikipedia.org/wiki/Median)
+pub fn median_fast(array: &[f64]) -> Option<f64> {
+    let n = array.len();
+    let upper_middle = quickselect_by(array, n / 2, f64::total_cmp)?;
+    if n % 2 == 1 {
+        return Some(upper_middle);
+    }
+
+    let lower_middle = quickselect_by(array, n / 2 - 1, f64::total_cmp)?;
+    Some((lower_middle + upper_middle) / 2.)
+}
+
+/// Optimal sorting network for 4 elements (5 compare-exchanges, depth 3).
+const SORT_NETWORK_4: [(usize, usize); 5] = [(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)];
+
+/// Optimal sorting network for 8 elements (19 compare-exchanges, depth 6).
+const SORT_NETWORK_8: [(usize, usize); 19] = [
+    (0, 2),
+    (1, 3),
+    (4, 6),
+    (5, 7),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+    (0, 1),
+    (2, 3),
+    (4, 5),
+    (6, 7),
+    (2, 4),
+    (3, 5),
+    (1, 4),
+    (3, 6),
+    (1, 2),
+    (3, 4),
+    (5, 6),
+];
+
+/// Applies the compare-exchanges of a sorting network to an array.
+fn apply_sort_network<T: Ord>(array: &mut [T], network: &[(usize, usize)]) {
+    for &(i, j) in network {
+        if array[i] > array[j] {
+            array.swap(i, j);
+        }
+    }
+}
+
+/// Sorts an array of 4 elements with an optimal sorting network.
+///
+/// The sequence of compare-exchanges is fixed and independent of the data,
+/// which makes it a fast building block for sorting small blocks.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Array of 4 elements to sort
+///
+/// # Complexity
+/// 5 comparisons
+///
+/// # Example
+/// ```
+/// let mut arr = [3, 1, 4, 2];
+/// sort_network_4(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4]);
+/// ```
+///
+/// # Reference
+/// [Sorting network - Wikipedia](https://en.wikipedia.org/wiki/Sorting_network#Optimal_sorting_networks)
+pub fn sort_network_4<T: Ord>(array: &mut [T; 4]) {
+    apply_sort_network(array, &SORT_NETWORK_4);
+}
+
+/// Sorts an array of 8 elements with an optimal sorting network.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Array of 8 elements to sort
+///
+/// # Complexity
+/// 19 comparisons
+///
+/// # Example
+/// ```
+/// let mut arr = [7, 3, 0, 5, 1, 6, 2, 4];
+/// sort_network_8(&mut arr);
+/// assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6, 7]);
+/// ```
+///
+/// # Reference
+/// [Sorting network - Wikipedia](https://en.wikipedia.org/wiki/Sorting_network#Optimal_sorting_networks)
+pub fn sort_network_8<T: Ord>(array: &mut [T; 8]) {
+    apply_sort_network(array, &SORT_NETWORK_8);
+}
+
+/// Number of consecutive wins of one run after which `tim_sort` merges switch to galloping mode.
+const TIM_SORT_MIN_GALLOP: usize = 7;
+
+/// Computes the minimum run length of `tim_sort` for an array of length `n`.
+///
+/// The result lies in [32, 64] (or is `n` for short arrays), chosen so that `n / min_run`
+/// is close to, and at most, a power of two, which balances the merges.
+fn tim_sort_min_run(mut n: usize) -> usize {
+    let mut remainder_bit = 0;
+    while n >= 64 {
+        remainder_bit |= n & 1;
+        n >>= 1;
+    }
+    n + remainder_bit
+}
+
+/// Counts the leading elements of a sorted slice satisfying `is_before`, by exponential
+/// then binary search, in O(log k) comparisons where k is the result.
+fn gallop<T, F>(slice: &[T], is_before: F) -> usize
+where
+    F: Fn(&T) -> bool,
+{
+    let mut bound = 1;
+    while bound <= slice.len() && is_before(&slice[bound - 1]) {
+        bound *= 2;
+    }
+    // The result lies in [bound / 2, min(bound, len)]
+    let low = bound / 2;
+    let high = bound.min(slice.len());
+    low + slice[low..high].partition_point(is_before)
+}
+
+/// Merges the adjacent sorted runs `array[low..mid]` and `array[mid..high]`, with galloping.
+fn tim_sort_merge<T>(array: &mut [T], low: usize, mid: usize, high: usize)
+where
+    T: Ord + Clone,
+{
+    // Elements of the left run not greater than the first right element are already in place,
+    // as well as elements of the right run not less than the last left element
+    let low = low + gallop(&array[low..mid], |x| *x <= array[mid]);
+    let high = mid + gallop(&array[mid..high], |x| *x < array[mid - 1]);
+    if low == mid || mid == high {
+        return;
+    }
+
+    let left: Vec<T> = array[low..mid].to_vec();
+    let mut i = 0; // Index in left
+    let mut j = mid; // Index in array (right run)
+    let mut k = low; // Output index in array, always <= j
+    let mut left_wins = 0;
+    let mut right_wins = 0;
+
+    while i < left.len() && j < high {
+        if left_wins >= TIM_SORT_MIN_GALLOP {
+            // Copy in bulk the left elements not greater than the next right element
+            let count = gallop(&left[i..], |x| *x <= array[j]);
+            array[k..k + count].clone_from_slice(&left[i..i + count]);
+            i += count;
+            k += count;
+            left_wins = 0;
+        } else if right_wins >= TIM_SORT_MIN_GALLOP {
+            // Copy in bulk the right elements less than the next left element
+            let count = gallop(&array[j..high], |x| *x < left[i]);
+            for _ in 0..count {
+                array[k] = array[j].clone();
+                j += 1;
+                k += 1;
+            }
+            right_wins = 0;
+        } else if left[i] <= array[j] {
+            // In case of equality, the left element comes first for stability
+            array[k] = left[i].clone();
+            i += 1;
+            k += 1;
+            left_wins += 1;
+            right_wins = 0;
+        } else {
+            array[k] = array[j].clone();
+            j += 1;
+            k += 1;
+            right_wins += 1;
+            left_wins = 0;
+        }
+    }
+
+    // Remaining right elements are already in place
+    let remaining = left.len() - i;
+    array[k..k + remaining].clone_from_slice(&left[i..]);
+}
+
+/// Generic Timsort: natural merge sort, efficient on partially sorted data.
+///
+/// The array is split into runs: maximal ascending runs, or strictly descending runs
+/// (reversed in place). Runs shorter than a minimum length (32 to 64) are extended with
+/// insertion sort. Runs are pushed on a stack and merged so that their lengths stay
+/// balanced. Merges switch to galloping mode when one run keeps winning, copying whole
+/// blocks found by exponential search.
+/// The sort is stable.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Complexity
+/// O(n log n) worst case, O(n) on sorted or reverse-sorted input, and close to O(n)
+/// for inputs made of a few sorted batches
+///
+/// # Example
+/// ```
+/// let mut arr = [1, 2, 3, 7, 8, 9, 4, 5, 6];
+/// tim_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+///
+/// # Reference
+/// [Timsort - Wikipedia](https://en.wikipedia.org/wiki/Timsort)
+pub fn tim_sort<T>(array: &mut [T])
+where
+    T: Ord + Clone,
+{
+    let n = array.len();
+    let min_run = tim_sort_min_run(n);
+    // Stack of runs, as (start, length)
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+
+    let mut start = 0;
+    while start < n {
+        // Find the natural run starting at start
+        let mut end = start + 1;
+        if end < n {
+            if array[end] < array[start] {
+                while end < n && array[end] < array[end - 1] {
+                    end += 1;
+                }
+                array[start..end].reverse();
+            } else {
+                while end < n && array[end] >= array[end - 1] {
+                    end += 1;
+                }
+            }
+        }
+
+        // Extend short runs with insertion sort
+        let run_end = (start + min_run).min(n).max(end);
+        for i in end..run_end {
+            let mut j = i;
+            while j > start && array[j] < array[j - 1] {
+                array.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+        runs.push((start, run_end - start));
+        start = run_end;
+
+        // Merge runs until the lengths on the stack satisfy:
+        // len[i - 2] > len[i - 1] + len[i] and len[i - 1] > len[i]
+        while runs.len() > 1 {
+            let top = runs.len() - 1;
+            let length = |index: usize| runs[index].1;
+            let mut merge_index = top - 1;
+            let invariant_broken = (top >= 2 && length(top - 2) <= length(top - 1) + length(top))
+                || (top >= 3 && length(top - 3) <= length(top - 2) + length(top - 1));
+            if invariant_broken {
+                if length(top - 2) < length(top) {
+                    merge_index = top - 2;
+                }
+            } else if length(top - 1) > length(top) {
+                break;
+            }
+            tim_sort_merge_at(array, &mut runs, merge_index);
+        }
+    }
+
+    // Merge all the remaining runs
+    while runs.len() > 1 {
+        let merge_index = runs.len() - 2;
+        tim_sort_merge_at(array, &mut runs, merge_index);
+    }
+}
+
+/// Merges the runs at indices `index` and `index + 1` of the `tim_sort` run stack.
+fn tim_sort_merge_at<T>(array: &mut [T], runs: &mut Vec<(usize, usize)>, index: usize)
+where
+    T: Ord + Clone,
+{
+    let (start, left_len) = runs[index];
+    let right_len = runs[index + 1].1;
+    tim_sort_merge(array, start, start + left_len, start + left_len + right_len);
+    runs[index] = (start, left_len + right_len);
+    runs.remove(index + 1);
+}
+
+/// Stooge sort: recursively sorts the first 2/3, then the last 2/3, then the first 2/3 again.
+///
+/// After the first two steps, the largest third of the elements is in place at the end;
+/// the third step then sorts the rest. The algorithm is provably correct but much slower
+/// than even bubble sort, and is only meant as a teaching example.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Complexity
+/// O(n^(log 3 / log 1.5)) ~ O(n^2.71) comparisons: only usable on small arrays
+///
+/// # Example
+/// ```
+/// let mut arr = [3, 1, 2];
+/// stooge_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+///
+/// # Reference
+/// [Stooge sort - Wikipedia](https://en.wikipedia.org/wiki/Stooge_sort)
+pub fn stooge_sort<T>(array: &mut [T])
+where
+    T: Ord,
+{
+    let n = array.len();
+    if n < 2 {
+        return;
+    }
+    if array[n - 1] < array[0] {
+        array.swap(0, n - 1);
+    }
+    if n > 2 {
+        // Two thirds, rounded up, so that the overlapping parts cover the whole array
+        let two_thirds = n - n / 3;
+        stooge_sort(&mut array[..two_thirds]);
+        stooge_sort(&mut array[n - two_thirds..]);
+        stooge_sort(&mut array[..two_thirds]);
+    }
+}
+
+/// Generic implementation of quick sort (in place, not stable).
+///
+/// The middle element is used as pivot, which avoids the quadratic worst case on
+/// already sorted arrays. Elements are split into three parts (smaller than, equal to
+/// and greater than the pivot), so that duplicated keys do not degrade the running time.
+/// Only the smaller part is sorted recursively, which bounds the recursion depth to O(log n).
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+///
+/// # Complexity
+/// O(n log n) average time complexity, O(n²) worst case
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// generic_quick_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// # Reference
+/// [Dutch national flag problem - Wikipedia](https://en.wikipedia.org/wiki/Dutch_national_flag_problem)
+pub fn generic_quick_sort<T: Ord>(array: &mut [T]) {
+    use std::cmp::Ordering;
+
+    let mut array = array;
+    while array.len() > 1 {
+        let n = array.len();
+
+        // Three-way partition: [0, lower) < pivot, [lower, upper) == pivot, [upper, n) > pivot
+        // The pivot is moved to the front, and array[lower] always holds a copy of it
+        array.swap(0, n / 2);
+        let mut lower = 0;
+        let mut upper = n;
+        let mut i = 1;
+        while i < upper {
+            match array[i].cmp(&array[lower]) {
+                Ordering::Less => {
+                    array.swap(i, lower);
+                    lower += 1;
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    upper -= 1;
+                    array.swap(i, upper);
+                }
+                Ordering::Equal => i += 1,
+            }
+        }
+
+        // Recurse into the smaller part and loop on the larger one
+        let (left_array, rest) = std::mem::take(&mut array).split_at_mut(lower);
+        let right_array = &mut rest[(upper - lower)..];
+        if left_array.len() < right_array.len() {
+            generic_quick_sort(left_array);
+            array = right_array;
+        } else {
+            generic_quick_sort(right_array);
+            array = left_array;
+        }
+    }
+}
+
+/// Sorting algorithms available through `sort`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortAlgorithm {
+    /// Insertion sort (`generic_insertion_sort`), stable
+    Insertion,
+    /// Merge sort (`tim_sort`), stable
+    #[default]
+    Merge,
+    /// Quick sort (`generic_quick_sort`), not stable
+    Quick,
+    /// Heap sort (`sorting::heap_sort`), not stable
+    Heap,
+}
+
+impl SortAlgorithm {
+    /// Whether the algorithm keeps equal elements in their original order.
+    pub fn is_stable(&self) -> bool {
+        matches!(self, SortAlgorithm::Insertion | SortAlgorithm::Merge)
+    }
+}
+
+/// Options of `sort`.
+///
+/// The default options sort in ascending order with merge sort.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortOptions {
+    /// Algorithm to use
+    pub algorithm: SortAlgorithm,
+    /// Sort from the largest to the smallest element
+    pub descending: bool,
+    /// Require a stable sort: an unstable algorithm is then replaced by merge sort
+    pub stable: bool,
+}
+
+/// Sorts an array with the given algorithm, in the given direction.
+///
+/// # Type Parameters
+/// * `T` - Type of elements to sort (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `array` - Mutable slice of elements to sort
+/// * `options` - Algorithm, direction and stability requirement
+///
+/// # Note
+/// The descending order reverses the comparison (elements are wrapped in `Reverse`),
+/// rather than reversing the sorted array: with a stable algorithm, equal elements keep
+/// their original order in both directions.
+///
+/// # Example
+/// ```
+/// let mut arr = [5, 2, 4, 6, 1, 3];
+/// let options = SortOptions { algorithm: SortAlgorithm::Heap, descending: true, stable: false };
+/// sort(&mut arr, options);
+/// assert_eq!(arr, [6, 5, 4, 3, 2, 1]);
+/// ```
+pub fn sort<T>(array: &mut [T], options: SortOptions)
+where
+    T: Ord + Clone,
+{
+    let algorithm = if options.stable && !options.algorithm.is_stable() {
+        SortAlgorithm::Merge
+    } else {
+        options.algorithm
+    };
+
+    if options.descending {
+        let mut reversed: Vec<Reverse<T>> = array.iter().cloned().map(Reverse).collect();
+        sort_with_algorithm(&mut reversed, algorithm);
+        for (elem, Reverse(value)) in array.iter_mut().zip(reversed) {
+            *elem = value;
+        }
+    } else {
+        sort_with_algorithm(array, algorithm);
+    }
+}
+
+/// Sorts an array in ascending order with the given algorithm.
+fn sort_with_algorithm<T>(array: &mut [T], algorithm: SortAlgorithm)
+where
+    T: Ord + Clone,
+{
+    match algorithm {
+        SortAlgorithm::Insertion => generic_insertion_sort(array),
+        SortAlgorithm::Merge => tim_sort(array),
+        SortAlgorithm::Quick => generic_quick_sort(array),
+        SortAlgorithm::Heap => heap_sort(array),
+    }
+}
+
+/// Selects the `k` largest elements of a stream, with memory bounded by `k`.
+///
+/// A min-heap keeps the `k` largest elements seen so far: each new element is pushed,
+/// and the smallest one is popped when the heap exceeds `k` elements.
+///
+/// # Type Parameters
+/// * `T` - Type of elements (must implement Ord and Clone)
+///
+/// # Arguments
+/// * `iter` - The stream of elements
+/// * `k` - Number of elements to keep
+///
+/// # Returns
+/// The `k` largest elements (or all of them if the stream is shorter), in descending order
+///
+/// # Complexity
+/// O(n log k) time and O(k) memory for a stream of n elements
+///
+/// # Example
+/// ```
+/// assert_eq!(top_k(0..1000, 3), vec![999, 998, 997]);
+/// ```
+pub fn top_k<T>(iter: impl Iterator<Item = T>, k: usize) -> Vec<T>
+where
+    T: Ord + Clone,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap: BinaryHeap<Reverse<T>> = BinaryHeap::with_capacity(k + 1);
+    for elem in iter {
+        heap.push(Reverse(elem));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    // Ascending order of Reverse is the descending order of the elements
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(elem)| elem)
+        .collect()
+}
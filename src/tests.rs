@@ -1,6 +1,52 @@
 use crate::classics;
+use crate::data_structures;
+use crate::files;
+use crate::fractals;
+use crate::graphs_mazes;
 use crate::misc;
+use crate::numerical_methods;
+use crate::probabilities;
 use crate::rational;
+use crate::sorting;
+use crate::sorting_variants;
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+/// Wrapper counting the comparisons made by a sorting algorithm
+#[derive(Clone)]
+struct CountedCmp<'a> {
+    value: i32,
+    counter: &'a Cell<usize>,
+}
+
+impl PartialEq for CountedCmp<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CountedCmp<'_> {}
+
+impl PartialOrd for CountedCmp<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CountedCmp<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.counter.set(self.counter.get() + 1);
+        self.value.cmp(&other.value)
+    }
+}
+
+fn counted_array<'a>(values: &[i32], counter: &'a Cell<usize>) -> Vec<CountedCmp<'a>> {
+    values
+        .iter()
+        .map(|&value| CountedCmp { value, counter })
+        .collect()
+}
 
 #[test]
 fn test_classics_factorial() {
@@ -28,6 +74,34 @@ fn test_classics_recursive_fibonacci() {
     assert_eq!(classics::fibonacci_recursive(15), 610);
 }
 
+#[test]
+fn test_classics_fibonacci_iter() {
+    let first_values: Vec<u64> = classics::FibonacciIter::new().take(10).collect();
+    assert_eq!(first_values, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+
+    // F(0) to F(93) fit in a u64, then the iteration stops
+    let all_values: Vec<u64> = classics::FibonacciIter::new().collect();
+    assert_eq!(all_values.len(), 94);
+    assert_eq!(all_values[93], 12200160415121876738);
+    assert_eq!(all_values[50], classics::fibonacci_iterative(50));
+}
+
+#[test]
+fn test_classics_fibonacci_mod() {
+    for n in 0..60 {
+        assert_eq!(
+            classics::fibonacci_mod(n, 1000),
+            classics::fibonacci_iterative(n) % 1000
+        );
+    }
+    assert_eq!(
+        classics::fibonacci_mod(90, u64::MAX),
+        classics::fibonacci_iterative(90)
+    );
+    assert_eq!(classics::fibonacci_mod(100, 1_000_000_007), 687995182);
+    assert_eq!(classics::fibonacci_mod(10, 1), 0);
+}
+
 #[test]
 fn test_classics_linear_search() {
     let array: &[i32] = &[5, 10, 3, 7, 15];
@@ -106,3 +180,2492 @@ fn test_rationals() {
         "Failed test_rationals (4): Reference division."
     );
 }
+
+#[test]
+fn test_misc_classify_number() {
+    assert_eq!(misc::sum_of_proper_divisors(28), 28);
+    assert_eq!(misc::sum_of_proper_divisors(12), 16);
+    assert_eq!(misc::sum_of_proper_divisors(1), 0);
+    assert_eq!(misc::classify_number(6), misc::NumberClass::Perfect);
+    assert_eq!(misc::classify_number(28), misc::NumberClass::Perfect);
+    assert_eq!(misc::classify_number(12), misc::NumberClass::Abundant);
+    assert_eq!(misc::classify_number(8), misc::NumberClass::Deficient);
+}
+
+#[test]
+fn test_misc_find_primes_segmented() {
+    assert_eq!(
+        misc::find_primes_segmented(0, 30, 7),
+        misc::find_primes(0, 30)
+    );
+    assert_eq!(misc::find_primes_segmented(10, 20, 4), vec![11, 13, 17, 19]);
+    assert_eq!(
+        misc::find_primes_segmented(1000000, 1010000, 1000),
+        misc::find_primes(1000000, 1010000)
+    );
+}
+
+#[test]
+fn test_misc_knapsack_01() {
+    let (value, items) = misc::knapsack_01(&[1, 3, 4, 5], &[1, 4, 5, 7], 7);
+    assert_eq!(value, 9);
+    // Not [1, 3] as in the original request: items 1 and 3 weigh 3 + 5 = 8 > capacity 7
+    assert_eq!(items, vec![1, 2]);
+
+    let (value, items) = misc::knapsack_01(&[10, 20], &[5, 6], 5);
+    assert_eq!(value, 0);
+    assert!(items.is_empty());
+}
+
+#[test]
+fn test_misc_longest_common_subsequence() {
+    // Checks that `sub` can be obtained from `seq` by deleting elements
+    fn is_subsequence<T: PartialEq>(sub: &[T], seq: &[T]) -> bool {
+        let mut seq_iter = seq.iter();
+        sub.iter().all(|x| seq_iter.any(|y| y == x))
+    }
+
+    let a = ['A', 'B', 'C', 'B', 'D', 'A', 'B'];
+    let b = ['B', 'D', 'C', 'A', 'B', 'A'];
+    let lcs = misc::longest_common_subsequence(&a, &b);
+    assert_eq!(lcs.len(), 4);
+    assert!(is_subsequence(&lcs, &a));
+    assert!(is_subsequence(&lcs, &b));
+
+    let lcs = misc::longest_common_subsequence(&[1, 2, 3, 4, 5], &[0, 2, 4, 5, 6]);
+    assert_eq!(lcs, vec![2, 4, 5]);
+    assert!(misc::longest_common_subsequence(&[1, 2], &[3, 4]).is_empty());
+    assert!(misc::longest_common_subsequence::<i32>(&[], &[1]).is_empty());
+}
+
+#[test]
+fn test_misc_levenshtein_distance() {
+    assert_eq!(misc::levenshtein_distance("kitten", "sitting"), 3);
+    assert_eq!(misc::levenshtein_distance("sitting", "kitten"), 3);
+    assert_eq!(misc::levenshtein_distance("rust", "rust"), 0);
+    assert_eq!(misc::levenshtein_distance("", "abc"), 3);
+    assert_eq!(misc::levenshtein_distance("abc", ""), 3);
+    assert_eq!(misc::levenshtein_distance("abc", "xyz"), 3);
+}
+
+#[test]
+fn test_misc_nth_prime() {
+    let first_primes: [u64; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+    for (i, &p) in first_primes.iter().enumerate() {
+        assert_eq!(misc::nth_prime(i + 1), p);
+    }
+    assert_eq!(misc::nth_prime(1000), 7919);
+    assert_eq!(misc::nth_prime(10001), 104743);
+}
+
+#[test]
+fn test_misc_permutations() {
+    let mut all_permutations = misc::permutations(&[1, 2, 3]);
+    assert_eq!(all_permutations.len(), 6);
+    all_permutations.sort();
+    all_permutations.dedup();
+    assert_eq!(all_permutations.len(), 6);
+    assert_eq!(misc::permutations::<i32>(&[]).len(), 1);
+
+    // next_permutation cycles through the same orderings in lexicographic order
+    let mut items = [1, 2, 3];
+    let mut lex_permutations = vec![items.to_vec()];
+    while misc::next_permutation(&mut items) {
+        lex_permutations.push(items.to_vec());
+    }
+    assert_eq!(lex_permutations, all_permutations);
+    assert_eq!(items, [1, 2, 3]);
+}
+
+#[test]
+fn test_misc_combinations() {
+    assert_eq!(
+        misc::combinations(&[1, 2, 3, 4], 2),
+        vec![
+            vec![1, 2],
+            vec![1, 3],
+            vec![1, 4],
+            vec![2, 3],
+            vec![2, 4],
+            vec![3, 4]
+        ]
+    );
+    assert_eq!(misc::combinations(&[1, 2, 3], 0), vec![Vec::<i32>::new()]);
+    assert_eq!(misc::combinations(&[1, 2, 3], 3), vec![vec![1, 2, 3]]);
+    assert!(misc::combinations(&[1, 2, 3], 4).is_empty());
+}
+
+#[test]
+fn test_fractals_write_pgm() {
+    let file_path: String = std::env::temp_dir()
+        .join("rust_algorithms_test_fractal.pgm")
+        .to_string_lossy()
+        .into_owned();
+    fractals::calculate_fractal_and_write_pgm(0.3, 0.5, &file_path);
+
+    let content: Vec<u8> = files::read_binary_file(&file_path);
+    let header: &[u8] = b"P5\n640 480\n255\n";
+    assert_eq!(&content[..header.len()], header);
+    assert_eq!(content.len(), header.len() + 640 * 480);
+}
+
+#[test]
+fn test_fractals_render_fractal_viewport() {
+    let default_params = fractals::FractalParams {
+        width: 32,
+        height: 24,
+        ..fractals::FractalParams::new(0.3, 0.5)
+    };
+    let zoomed_params = fractals::FractalParams {
+        x_min: -0.1,
+        x_max: 0.1,
+        y_min: -0.1,
+        y_max: 0.1,
+        ..default_params.clone()
+    };
+
+    let default_pixels = fractals::render_fractal(default_params);
+    let zoomed_pixels = fractals::render_fractal(zoomed_params);
+    assert_eq!(default_pixels.len(), 32 * 24);
+    assert_eq!(zoomed_pixels.len(), 32 * 24);
+    assert_ne!(default_pixels, zoomed_pixels);
+}
+
+#[test]
+fn test_graphs_mazes_render_solution() {
+    let layout: Vec<String> = vec!["@  |".to_string(), " | |".to_string(), "   $".to_string()];
+    let visited: Vec<(u32, u32)> = vec![(1, 0), (2, 0), (0, 1), (0, 2)];
+    let path: Vec<(u32, u32)> = vec![(0, 1), (0, 2), (1, 2), (2, 2)];
+
+    let solution = graphs_mazes::render_solution(&layout, &visited, &path);
+    assert_eq!(solution, vec!["@oo|", "x| |", "xxx$"]);
+
+    let colored = graphs_mazes::colorize(&solution);
+    assert_eq!(colored.len(), 3);
+    assert_eq!(
+        colored[2],
+        "\x1b[93mx\x1b[0m".repeat(3) + "\x1b[92m$\x1b[0m"
+    );
+}
+
+#[test]
+fn test_probabilities_rng_state_checkpoint() {
+    let mut rng = probabilities::MinstdRng::new(1234);
+    for _ in 0..100 {
+        rng.gen();
+    }
+
+    let checkpoint: u32 = rng.state();
+    let continuation: Vec<u32> = (0..50).map(|_| rng.gen()).collect();
+
+    rng.set_state(checkpoint);
+    let replayed: Vec<u32> = (0..50).map(|_| rng.gen()).collect();
+    assert_eq!(continuation, replayed);
+
+    // A fresh generator restored from the checkpoint yields the same continuation
+    let mut other_rng = probabilities::MinstdRng::new(1);
+    other_rng.set_state(checkpoint);
+    assert_eq!(other_rng.gen(), continuation[0]);
+}
+
+#[test]
+#[should_panic]
+fn test_probabilities_rng_set_state_multiple_of_modulus() {
+    let mut rng = probabilities::MinstdRng::new(42);
+    rng.set_state(0xFFFFFFFE);
+}
+
+#[test]
+fn test_probabilities_rng_jump() {
+    let mut stepped_rng = probabilities::MinstdRng::new(1234);
+    for _ in 0..1000 {
+        stepped_rng.gen();
+    }
+
+    let mut jumped_rng = probabilities::MinstdRng::new(1234);
+    jumped_rng.jump(1000);
+    assert_eq!(jumped_rng.state(), stepped_rng.state());
+    assert_eq!(jumped_rng.gen(), stepped_rng.gen());
+
+    let mut unchanged_rng = probabilities::MinstdRng::new(1234);
+    unchanged_rng.jump(0);
+    assert_eq!(unchanged_rng.state(), 1234);
+
+    assert_eq!(misc::mod_pow(3, 4, 7), 4);
+    assert_eq!(misc::mod_pow(2, 64, u64::MAX), 1);
+}
+
+#[test]
+fn test_probabilities_rng_gen_bool() {
+    let mut rng = probabilities::MinstdRng::new(1234);
+    for _ in 0..1000 {
+        let u = rng.gen_f64();
+        assert!((0. ..1.).contains(&u));
+        assert!(rng.gen_bool(1.0));
+        assert!(!rng.gen_bool(0.0));
+    }
+
+    let n_draws = 1000000;
+    let n_true = (0..n_draws).filter(|_| rng.gen_bool(0.3)).count();
+    let true_rate = (n_true as f64) / (n_draws as f64);
+    assert!((true_rate - 0.3).abs() < 0.005, "true rate: {}", true_rate);
+}
+
+#[test]
+fn test_probabilities_sample_without_replacement() {
+    let items: Vec<i32> = (0..100).collect();
+    let mut sample = probabilities::sample_without_replacement(&items, 10, 1234);
+    assert_eq!(sample.len(), 10);
+    assert_eq!(
+        sample,
+        probabilities::sample_without_replacement(&items, 10, 1234)
+    );
+
+    sample.sort();
+    sample.dedup();
+    assert_eq!(sample.len(), 10);
+
+    let mut full_sample = probabilities::sample_without_replacement(&items, 100, 1);
+    full_sample.sort();
+    assert_eq!(full_sample, items);
+}
+
+#[test]
+fn test_probabilities_histogram() {
+    assert_eq!(
+        probabilities::histogram(&[0.5, 1.5, 1.6], 2, 0.0, 2.0),
+        vec![1, 2]
+    );
+    // Out of range values are clamped into the first / last bin
+    assert_eq!(
+        probabilities::histogram(&[-3.0, 0.1, 2.0, 5.0], 2, 0.0, 2.0),
+        vec![2, 2]
+    );
+
+    let mut rng = probabilities::MinstdRng::new(1234);
+    let uniforms: Vec<f64> = (0..100000).map(|_| rng.gen_f64()).collect();
+    let counts = probabilities::histogram(&uniforms, 10, 0.0, 1.0);
+    assert_eq!(counts.iter().sum::<usize>(), 100000);
+    for count in counts {
+        assert!((9500..10500).contains(&count), "count: {}", count);
+    }
+}
+
+#[test]
+fn test_graphs_mazes_minimum_spanning_tree() {
+    let mut graph: graphs_mazes::Graph<char, u32> = graphs_mazes::Graph::new();
+    graph.add_edge('a', 'b', 2);
+    graph.add_edge('a', 'd', 6);
+    graph.add_edge('b', 'c', 3);
+    graph.add_edge('b', 'd', 8);
+    graph.add_edge('b', 'e', 5);
+    graph.add_edge('c', 'e', 7);
+    graph.add_edge('d', 'e', 9);
+
+    let tree = graphs_mazes::minimum_spanning_tree(&graph);
+    assert_eq!(tree.len(), 4);
+    assert_eq!(tree.iter().map(|edge| edge.2).sum::<u32>(), 16);
+    assert!(tree.contains(&('a', 'd', 6)));
+}
+
+#[test]
+fn test_graphs_mazes_union_find() {
+    let mut sets = graphs_mazes::UnionFind::new(8);
+    assert_eq!(sets.component_count(), 8);
+
+    assert!(sets.union(0, 1));
+    assert!(sets.union(1, 2));
+    assert!(sets.union(3, 4));
+    assert!(sets.union(5, 6));
+    assert!(sets.union(6, 4));
+    // Already in the same set
+    assert!(!sets.union(0, 2));
+    assert!(!sets.union(3, 5));
+
+    // Groups: {0, 1, 2}, {3, 4, 5, 6}, {7}
+    assert!(sets.connected(0, 2));
+    assert!(sets.connected(3, 6));
+    assert!(!sets.connected(2, 3));
+    assert!(!sets.connected(7, 0));
+    assert_eq!(sets.find(5), sets.find(3));
+    assert_eq!(sets.component_count(), 3);
+}
+
+#[test]
+fn test_graphs_mazes_dijkstra_bidirectional() {
+    let maze_directory = "divers/labyrinthes".to_string();
+    for maze_file in files::list_directory(&maze_directory) {
+        if maze_file.contains("solution") {
+            continue;
+        }
+        let maze_layout: Vec<String> = files::read_text_file_lines(&maze_file, None);
+        let maze = graphs_mazes::Maze::new(&maze_layout);
+        let start = maze.start_positions()[0];
+        let end = maze.end_positions()[0];
+
+        let result = graphs_mazes::solve_dijkstra(&maze, vec![start], vec![end]);
+        let (distances, end_vertex) = (result.distances, result.reached_end);
+        let bidirectional_result = graphs_mazes::solve_dijkstra_bidirectional(&maze, start, end);
+
+        match end_vertex {
+            Some(end_vertex) => {
+                let (distance, path) = bidirectional_result.unwrap();
+                assert_eq!(distance, distances[&end_vertex], "maze: {}", maze_file);
+                assert_eq!(path[0], start);
+                assert_eq!(path[path.len() - 1], end);
+                assert_eq!((path.len() - 1) as u64, distance);
+            }
+            None => assert!(bidirectional_result.is_none()),
+        }
+    }
+
+    // Weighted graph where the direct edge is not the shortest path
+    let mut graph: graphs_mazes::Graph<char, u32> = graphs_mazes::Graph::new();
+    graph.add_edge('a', 'b', 1);
+    graph.add_edge('b', 'c', 1);
+    graph.add_edge('c', 'd', 1);
+    graph.add_edge('a', 'd', 5);
+    graph.add_vertex('z');
+    assert_eq!(
+        graphs_mazes::solve_dijkstra_bidirectional(&graph, 'a', 'd'),
+        Some((3, vec!['a', 'b', 'c', 'd']))
+    );
+    assert_eq!(
+        graphs_mazes::solve_dijkstra_bidirectional(&graph, 'a', 'z'),
+        None
+    );
+}
+
+#[test]
+fn test_sorting_variants_duplicates() {
+    let array: &[i32] = &[3, 1, 2, 3, 1, 3];
+    assert!(sorting_variants::has_duplicates(array));
+    assert_eq!(sorting_variants::find_duplicates(array), vec![1, 3]);
+
+    let array: &[i32] = &[5, 2, 8];
+    assert!(!sorting_variants::has_duplicates(array));
+    assert!(sorting_variants::find_duplicates(array).is_empty());
+    assert!(!sorting_variants::has_duplicates::<i32>(&[]));
+}
+
+#[test]
+fn test_sorting_variants_quickselect() {
+    let mut array: Vec<i32> = (0..1001).collect();
+    probabilities::fisher_yates_shuffle(&mut array, 1234);
+    let array_copy = array.clone();
+    assert_eq!(sorting_variants::quickselect(&array, 500), Some(500));
+    assert_eq!(array, array_copy);
+
+    let array: &[i32] = &[7, 3, 3, 9, 1, 3, 8, 7];
+    let mut sorted_array = array.to_vec();
+    sorted_array.sort();
+    for (k, &value) in sorted_array.iter().enumerate() {
+        assert_eq!(sorting_variants::quickselect(array, k), Some(value));
+    }
+    assert_eq!(sorting_variants::quickselect(array, 8), None);
+}
+
+#[test]
+fn test_misc_rotate() {
+    let mut array = [1, 2, 3, 4, 5];
+    misc::rotate_left(&mut array, 2);
+    assert_eq!(array, [3, 4, 5, 1, 2]);
+    misc::rotate_right(&mut array, 2);
+    assert_eq!(array, [1, 2, 3, 4, 5]);
+
+    misc::rotate_left(&mut array, 0);
+    assert_eq!(array, [1, 2, 3, 4, 5]);
+    misc::rotate_left(&mut array, 5);
+    assert_eq!(array, [1, 2, 3, 4, 5]);
+    misc::rotate_right(&mut array, 7);
+    assert_eq!(array, [4, 5, 1, 2, 3]);
+
+    let mut empty_array: [i32; 0] = [];
+    misc::rotate_left(&mut empty_array, 3);
+    misc::rotate_right(&mut empty_array, 3);
+}
+
+#[test]
+fn test_misc_run_length_encoding() {
+    let data = ['a', 'a', 'a', 'b', 'c', 'c'];
+    let encoded = misc::run_length_encode(&data);
+    assert_eq!(encoded, vec![('a', 3), ('b', 1), ('c', 2)]);
+    assert_eq!(misc::run_length_decode(&encoded), data);
+    assert!(misc::run_length_encode::<u8>(&[]).is_empty());
+
+    // Round trip over random byte sequences (small alphabet, to get long runs)
+    let mut rng = probabilities::MinstdRng::new(1234);
+    for _ in 0..20 {
+        let length = rng.gen_range(0..200) as usize;
+        let bytes: Vec<u8> = (0..length).map(|_| rng.gen_range(0..3) as u8).collect();
+        let encoded = misc::run_length_encode(&bytes);
+        assert_eq!(misc::run_length_decode(&encoded), bytes);
+    }
+}
+
+#[test]
+fn test_misc_prime_iter() {
+    let first_primes: Vec<u64> = misc::PrimeIter::new().take(20).collect();
+    assert_eq!(
+        first_primes,
+        vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71]
+    );
+
+    let primes_below_1000: Vec<u64> = misc::PrimeIter::new().take_while(|&p| p < 1000).collect();
+    let expected: Vec<u64> = misc::find_primes(0, 1000)
+        .iter()
+        .map(|&p| p as u64)
+        .collect();
+    assert_eq!(primes_below_1000, expected);
+}
+
+#[test]
+fn test_misc_power_set() {
+    assert_eq!(
+        misc::power_set(&[1, 2]),
+        vec![vec![], vec![1], vec![2], vec![1, 2]]
+    );
+
+    let mut subsets = misc::power_set(&['a', 'b', 'c']);
+    assert_eq!(subsets.len(), 8);
+    assert!(subsets[0].is_empty());
+    subsets.sort();
+    subsets.dedup();
+    assert_eq!(subsets.len(), 8);
+
+    assert_eq!(misc::power_set::<i32>(&[]), vec![Vec::<i32>::new()]);
+}
+
+#[test]
+fn test_misc_subset_sum() {
+    let items: &[i64] = &[3, 34, 4, 12, 5, 2];
+    assert_eq!(misc::subset_sum(items, 9), Some(vec![2, 4]));
+    assert_eq!(misc::subset_sum(items, 30), None);
+    assert_eq!(misc::subset_sum(items, 0), Some(vec![]));
+
+    // Signed items: backtracking
+    let items: &[i64] = &[-7, -3, -2, 5, 8];
+    let indices = misc::subset_sum(items, 1).unwrap();
+    assert_eq!(indices.iter().map(|&i| items[i]).sum::<i64>(), 1);
+    let indices = misc::subset_sum(items, -4).unwrap();
+    assert_eq!(indices.iter().map(|&i| items[i]).sum::<i64>(), -4);
+    assert_eq!(misc::subset_sum(items, 100), None);
+
+    // Huge targets: no table allocation
+    assert_eq!(misc::subset_sum(&[1], 1_000_000_000_000), None);
+    let items: &[i64] = &[1, 3_000_000_000, 5_000_000_000];
+    assert_eq!(misc::subset_sum(items, 8_000_000_000), Some(vec![1, 2]));
+
+    // Signed items near the i64 bounds: no overflow
+    let items: &[i64] = &[i64::MAX, -1];
+    assert_eq!(misc::subset_sum(items, i64::MIN), None);
+    assert_eq!(misc::subset_sum(items, i64::MAX - 1), Some(vec![0, 1]));
+}
+
+#[test]
+fn test_numerical_methods_matrix() {
+    let a = numerical_methods::Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let b = numerical_methods::Matrix::from_vec(3, 2, vec![7, 8, 9, 10, 11, 12]);
+    let product = a.multiply(&b).unwrap();
+    assert_eq!(
+        product,
+        numerical_methods::Matrix::from_vec(2, 2, vec![58, 64, 139, 154])
+    );
+
+    let identity: numerical_methods::Matrix<i32> = numerical_methods::Matrix::identity(3);
+    assert_eq!(a.multiply(&identity).unwrap(), a);
+    assert!(a.multiply(&a).is_err());
+
+    let transposed = a.transpose();
+    assert_eq!((transposed.rows(), transposed.cols()), (3, 2));
+    assert_eq!(*transposed.get(2, 1), 6);
+    assert_eq!(transposed.transpose(), a);
+}
+
+#[test]
+fn test_numerical_methods_newton_raphson() {
+    let sqrt_2 =
+        numerical_methods::newton_raphson(|x| x * x - 2., |x| 2. * x, 1., 1e-12, 50).unwrap();
+    assert!((sqrt_2 - std::f64::consts::SQRT_2).abs() < 1e-8);
+
+    // x^3 - 2x + 2 from x0 = 0 cycles between 0 and 1
+    let cycling = numerical_methods::newton_raphson(
+        |x| x * x * x - 2. * x + 2.,
+        |x| 3. * x * x - 2.,
+        0.,
+        1e-12,
+        100,
+    );
+    assert_eq!(cycling, None);
+
+    // Zero derivative at the initial guess
+    assert_eq!(
+        numerical_methods::newton_raphson(|x| x * x + 1., |x| 2. * x, 0., 1e-12, 100),
+        None
+    );
+}
+
+#[test]
+fn test_numerical_methods_simpson_integrate() {
+    let tolerance = 1e-9;
+    let integral = numerical_methods::simpson_integrate(|x| x * x, 0., 1., 100);
+    assert!((integral - 1. / 3.).abs() < tolerance);
+
+    // Exact for polynomials up to degree 3, even with few subintervals
+    let integral = numerical_methods::simpson_integrate(|x| x * x * x - x + 2., -1., 2., 2);
+    assert!((integral - 8.25).abs() < tolerance);
+
+    let integral = numerical_methods::simpson_integrate(f64::sin, 0., std::f64::consts::PI, 100);
+    assert!((integral - 2.).abs() < 1e-7);
+
+    // Odd n is rounded up
+    let integral = numerical_methods::simpson_integrate(|x| 3. * x * x, 0., 2., 3);
+    assert!((integral - 8.).abs() < tolerance);
+}
+
+#[test]
+fn test_sorting_variants_generic_selection_sort() {
+    let mut array: Vec<i32> = (0..200).collect();
+    probabilities::fisher_yates_shuffle(&mut array, 42);
+    sorting_variants::generic_selection_sort(&mut array);
+    assert!(sorting::is_array_sorted(&array));
+    assert_eq!(array, (0..200).collect::<Vec<i32>>());
+
+    let mut array: Vec<String> = ["pear", "apple", "fig", "banana", "apple"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    sorting_variants::generic_selection_sort(&mut array);
+    assert!(sorting::is_array_sorted(&array));
+    assert_eq!(array, ["apple", "apple", "banana", "fig", "pear"]);
+
+    let mut array: [i32; 0] = [];
+    sorting_variants::generic_selection_sort(&mut array);
+}
+
+#[test]
+fn test_sorting_variants_bubble_sort() {
+    let mut array: Vec<i32> = (0..200).collect();
+    probabilities::fisher_yates_shuffle(&mut array, 42);
+    sorting_variants::bubble_sort(&mut array);
+    assert_eq!(array, (0..200).collect::<Vec<i32>>());
+
+    let mut array = ["pear", "apple", "fig", "banana", "apple"];
+    sorting_variants::bubble_sort(&mut array);
+    assert_eq!(array, ["apple", "apple", "banana", "fig", "pear"]);
+
+    // Early exit: a sorted input only needs a single pass of n - 1 comparisons
+    let counter = Cell::new(0);
+    let values: Vec<i32> = (0..100).collect();
+    let mut array = counted_array(&values, &counter);
+    sorting_variants::bubble_sort(&mut array);
+    assert_eq!(counter.get(), 99);
+    assert!(sorting::is_array_sorted(&array));
+}
+
+#[test]
+fn test_sorting_variants_cocktail_shaker_sort() {
+    let mut array: Vec<i32> = (0..200).rev().collect();
+    sorting_variants::cocktail_shaker_sort(&mut array);
+    assert_eq!(array, (0..200).collect::<Vec<i32>>());
+
+    let mut array: Vec<i32> = (0..201).collect();
+    probabilities::fisher_yates_shuffle(&mut array, 7);
+    sorting_variants::cocktail_shaker_sort(&mut array);
+    assert_eq!(array, (0..201).collect::<Vec<i32>>());
+
+    let mut array: [i32; 0] = [];
+    sorting_variants::cocktail_shaker_sort(&mut array);
+
+    // Turtle-heavy input: small values at the end.
+    // Both sorts make the same number of swaps (one per inversion), but the
+    // cocktail shaker sort needs far fewer passes, hence fewer comparisons.
+    let values: Vec<i32> = (2..100).chain([1, 0]).collect();
+    let bubble_counter = Cell::new(0);
+    let mut array = counted_array(&values, &bubble_counter);
+    sorting_variants::bubble_sort(&mut array);
+    assert!(sorting::is_array_sorted(&array));
+
+    let cocktail_counter = Cell::new(0);
+    let mut array = counted_array(&values, &cocktail_counter);
+    sorting_variants::cocktail_shaker_sort(&mut array);
+    assert!(sorting::is_array_sorted(&array));
+
+    assert!(5 * cocktail_counter.get() < bubble_counter.get());
+}
+
+#[test]
+fn test_sorting_variants_comb_sort() {
+    let mut array: Vec<i32> = (0..1000).collect();
+    probabilities::fisher_yates_shuffle(&mut array, 1234);
+    sorting_variants::comb_sort(&mut array);
+    assert_eq!(array, (0..1000).collect::<Vec<i32>>());
+
+    let mut array = ["pear", "apple", "fig", "banana", "apple"];
+    sorting_variants::comb_sort(&mut array);
+    assert_eq!(array, ["apple", "apple", "banana", "fig", "pear"]);
+
+    let mut array: [i32; 0] = [];
+    sorting_variants::comb_sort(&mut array);
+
+    // The gap sequence strictly decreases until it reaches 1
+    let mut gap = 1000;
+    let mut n_steps = 0;
+    while gap > 1 {
+        let next_gap = sorting_variants::comb_sort_next_gap(gap);
+        assert!(next_gap < gap);
+        gap = next_gap;
+        n_steps += 1;
+    }
+    assert_eq!(gap, 1);
+    assert!(n_steps <= 30);
+    assert_eq!(sorting_variants::comb_sort_next_gap(1), 1);
+}
+
+#[test]
+fn test_sorting_merge_sorted() {
+    assert_eq!(
+        sorting::merge_sorted(&[1, 3, 5], &[2, 4, 6]),
+        vec![1, 2, 3, 4, 5, 6]
+    );
+    assert_eq!(sorting::merge_sorted(&[1, 2, 3], &[]), vec![1, 2, 3]);
+    assert_eq!(sorting::merge_sorted(&[], &[1, 2, 3]), vec![1, 2, 3]);
+    assert!(sorting::merge_sorted::<i32>(&[], &[]).is_empty());
+
+    // Stability: on ties, elements from the first slice come first
+    let (a_counter, b_counter) = (Cell::new(0), Cell::new(0));
+    let a = counted_array(&[1, 2], &a_counter);
+    let b = counted_array(&[1, 3], &b_counter);
+    let merged = sorting::merge_sorted(&a, &b);
+    let from_a: Vec<bool> = merged
+        .iter()
+        .map(|elem| std::ptr::eq(elem.counter, &a_counter))
+        .collect();
+    assert_eq!(from_a, [true, false, true, false]);
+
+    let mut array = [5, 2, 4, 6, 1, 3, 2];
+    sorting::merge_sort(&mut array);
+    assert_eq!(array, [1, 2, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_sorting_variants_k_way_merge() {
+    let slices: [&[i32]; 4] = [&[1, 5, 9, 13], &[2, 6], &[], &[0, 3, 4, 7, 8, 10, 11, 12]];
+    let merged = sorting_variants::k_way_merge(&slices);
+    assert!(sorting::is_array_sorted(&merged));
+    assert_eq!(merged, (0..14).collect::<Vec<i32>>());
+
+    assert_eq!(
+        sorting_variants::k_way_merge(&[&[3, 3], &[1, 3]]),
+        vec![1, 3, 3, 3]
+    );
+    assert!(sorting_variants::k_way_merge::<i32>(&[]).is_empty());
+}
+
+#[test]
+fn test_misc_kmp_search() {
+    assert_eq!(misc::kmp_search("abcabab", "ab"), vec![0, 3, 5]);
+    assert_eq!(misc::kmp_search("aaaaa", "aa"), vec![0, 1, 2, 3]);
+    assert_eq!(misc::kmp_search("abababcabababcab", "ababcab"), vec![2, 9]);
+    assert!(misc::kmp_search("abcabab", "abd").is_empty());
+    assert!(misc::kmp_search("ab", "abc").is_empty());
+    assert_eq!(misc::kmp_search("abc", ""), vec![0, 1, 2, 3]);
+
+    // Indices are byte offsets, as with str::find
+    assert_eq!(misc::kmp_search("été", "t"), vec![2]);
+}
+
+#[test]
+fn test_misc_karatsuba_multiply() {
+    fn to_digits(mut n: u128) -> Vec<u8> {
+        let mut digits = vec![(n % 10) as u8];
+        n /= 10;
+        while n > 0 {
+            digits.push((n % 10) as u8);
+            n /= 10;
+        }
+        digits
+    }
+
+    assert_eq!(
+        misc::karatsuba_multiply(&to_digits(123456789), &to_digits(987654321)),
+        to_digits(121932631112635269)
+    );
+    assert_eq!(misc::karatsuba_multiply(&[0, 0], &[5, 1]), vec![0]);
+    assert_eq!(misc::karatsuba_multiply(&[], &[5, 1]), vec![0]);
+    assert_eq!(misc::karatsuba_multiply(&[2, 1, 0], &[4, 3]), vec![8, 0, 4]);
+
+    // Compare with native multiplication, products up to 38 digits
+    let mut rng = probabilities::MinstdRng::new(2024);
+    for _ in 0..100 {
+        let mut random_u128 = || {
+            let high = (rng.gen() as u128) << 62 | (rng.gen() as u128) << 31;
+            (high | rng.gen() as u128) % 10_000_000_000_000_000_000
+        };
+        let (a, b) = (random_u128(), random_u128());
+        assert_eq!(
+            misc::karatsuba_multiply(&to_digits(a), &to_digits(b)),
+            to_digits(a * b)
+        );
+    }
+
+    // (10^k - 1)^2 = 10^(2k) - 2 * 10^k + 1, i.e. digits 1, 0..0, 8, 9..9 (least significant first)
+    let k = 150;
+    let nines = vec![9; k];
+    let mut expected = vec![1];
+    expected.extend(vec![0; k - 1]);
+    expected.push(8);
+    expected.extend(vec![9; k - 1]);
+    assert_eq!(misc::karatsuba_multiply(&nines, &nines), expected);
+
+    // Large random factors: multiplying by 10^60 shifts the digits, and the product commutes
+    let a: Vec<u8> = (0..200)
+        .map(|_| (rng.gen() % 10) as u8)
+        .chain([7])
+        .collect();
+    let b: Vec<u8> = (0..130)
+        .map(|_| (rng.gen() % 10) as u8)
+        .chain([3])
+        .collect();
+    let mut power_of_ten = vec![0; 60];
+    power_of_ten.push(1);
+    let mut shifted = vec![0; 60];
+    shifted.extend(&a);
+    assert_eq!(misc::karatsuba_multiply(&a, &power_of_ten), shifted);
+    assert_eq!(
+        misc::karatsuba_multiply(&a, &b),
+        misc::karatsuba_multiply(&b, &a)
+    );
+}
+
+#[test]
+fn test_misc_majority_element() {
+    assert_eq!(misc::majority_element(&[2, 2, 1, 2, 3, 2]), Some(2));
+    assert_eq!(misc::majority_element(&[1, 2, 3]), None);
+    assert_eq!(misc::majority_element::<i32>(&[]), None);
+    assert_eq!(misc::majority_element(&["a"]), Some("a"));
+
+    // The final candidate 2 appears exactly half the time, which is not a majority
+    assert_eq!(misc::majority_element(&[2, 2, 1, 1]), None);
+    assert_eq!(misc::majority_element(&[1, 2, 3, 1, 1]), Some(1));
+}
+
+#[test]
+fn test_misc_max_subarray_sum() {
+    assert_eq!(
+        misc::max_subarray_sum(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]),
+        (6, 3, 6)
+    );
+    assert_eq!(misc::max_subarray_sum(&[-3, -1, -2]), (-1, 1, 1));
+    assert_eq!(misc::max_subarray_sum(&[5]), (5, 0, 0));
+    assert_eq!(misc::max_subarray_sum(&[1, 2, 3]), (6, 0, 2));
+    assert_eq!(misc::max_subarray_sum(&[2, -5, 1, 1, -1, 3]), (4, 2, 5));
+}
+
+#[test]
+fn test_classics_two_sum_sorted() {
+    assert_eq!(classics::two_sum_sorted(&[1, 2, 4, 7, 11], 9), Some((1, 3)));
+    assert_eq!(classics::two_sum_sorted(&[1, 2, 4, 7, 11], 10), None);
+    assert_eq!(classics::two_sum_sorted(&[1, 2, 4, 7, 11], 2), None);
+    assert_eq!(classics::two_sum_sorted(&[3, 3], 6), Some((0, 1)));
+    assert_eq!(classics::two_sum_sorted(&[1, 3, 3, 5], 6), Some((0, 3)));
+    assert_eq!(classics::two_sum_sorted(&[-4, -1, 0, 2], -5), Some((0, 1)));
+    assert_eq!(classics::two_sum_sorted(&[5], 10), None);
+    assert_eq!(classics::two_sum_sorted(&[], 0), None);
+}
+
+#[test]
+#[should_panic]
+fn test_classics_two_sum_sorted_unsorted() {
+    classics::two_sum_sorted(&[3, 1, 2], 3);
+}
+
+#[test]
+fn test_data_structures_indexed_priority_queue() {
+    let mut queue = data_structures::IndexedPriorityQueue::new();
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop_min(), None);
+
+    for (item, priority) in [('a', 5), ('b', 3), ('c', 8), ('d', 1), ('e', 7)] {
+        queue.push(item, priority);
+    }
+    assert_eq!(queue.len(), 5);
+    assert_eq!(queue.peek_min(), Some((&'d', &1)));
+
+    // Decrease 'c' below all others
+    assert!(queue.decrease_priority(&'c', 0));
+    assert_eq!(queue.priority(&'c'), Some(&0));
+    // Not a decrease, or unknown item
+    assert!(!queue.decrease_priority(&'e', 9));
+    assert!(!queue.decrease_priority(&'z', 0));
+
+    // Pushing a queued item replaces its priority
+    queue.push('a', 2);
+    assert_eq!(queue.len(), 5);
+
+    let mut popped = Vec::new();
+    while let Some(entry) = queue.pop_min() {
+        popped.push(entry);
+    }
+    assert_eq!(
+        popped,
+        vec![('c', 0), ('d', 1), ('a', 2), ('b', 3), ('e', 7)]
+    );
+    assert!(!queue.contains(&'a'));
+
+    // Random priorities come out sorted
+    let mut rng = probabilities::MinstdRng::new(99);
+    let mut queue = data_structures::IndexedPriorityQueue::default();
+    for item in 0..500 {
+        queue.push(item, rng.gen() % 1000);
+    }
+    for item in (0..500).step_by(3) {
+        let priority = *queue.priority(&item).unwrap();
+        queue.decrease_priority(&item, priority / 2);
+    }
+    let mut previous = 0;
+    while let Some((_, priority)) = queue.pop_min() {
+        assert!(priority >= previous);
+        previous = priority;
+    }
+}
+
+#[test]
+fn test_data_structures_trie() {
+    let mut trie = data_structures::Trie::new();
+    for word in ["cat", "car", "dog", "cart"] {
+        trie.insert(word);
+    }
+
+    assert!(trie.contains("cat"));
+    assert!(trie.contains("cart"));
+    assert!(!trie.contains("ca"));
+    assert!(!trie.contains("cats"));
+    assert!(!trie.contains(""));
+
+    assert_eq!(trie.words_with_prefix("ca"), vec!["car", "cart", "cat"]);
+    assert_eq!(trie.words_with_prefix("dog"), vec!["dog"]);
+    assert!(trie.words_with_prefix("x").is_empty());
+    assert_eq!(trie.words_with_prefix("").len(), 4);
+
+    trie.insert("");
+    assert!(trie.contains(""));
+}
+
+#[test]
+fn test_data_structures_lru_cache() {
+    let mut cache = data_structures::LruCache::new(3);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    cache.put("c", 3);
+    assert_eq!(cache.len(), 3);
+
+    // "a" becomes the most recently used, so "b" is evicted
+    assert_eq!(cache.get(&"a"), Some(1));
+    cache.put("d", 4);
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.get(&"b"), None);
+    assert_eq!(cache.get(&"a"), Some(1));
+    assert_eq!(cache.get(&"c"), Some(3));
+    assert_eq!(cache.get(&"d"), Some(4));
+
+    // Updating a value also marks it as recently used: "a" is now the oldest
+    cache.put("c", 30);
+    cache.put("e", 5);
+    assert_eq!(cache.get(&"a"), None);
+    assert_eq!(cache.get(&"c"), Some(30));
+
+    // Memoization use case
+    let mut cache = data_structures::LruCache::new(100);
+    for n in (1..1000).chain(1..1000) {
+        let flight_time = match cache.get(&n) {
+            Some(value) => value,
+            None => {
+                let value = misc::calculate_collatz_flight_time_and_max_altitude(n).0;
+                cache.put(n, value);
+                value
+            }
+        };
+        assert_eq!(
+            flight_time,
+            misc::calculate_collatz_flight_time_and_max_altitude(n).0
+        );
+    }
+    assert_eq!(cache.len(), cache.capacity());
+}
+
+#[test]
+fn test_data_structures_fenwick_tree() {
+    let mut tree = data_structures::FenwickTree::from_slice(&[1, 2, 3, 4, 5]);
+    assert_eq!(tree.len(), 5);
+    let prefix_sums: Vec<i64> = (0..5).map(|i| tree.prefix_sum(i)).collect();
+    assert_eq!(prefix_sums, [1, 3, 6, 10, 15]);
+
+    tree.update(2, -10);
+    let prefix_sums: Vec<i64> = (0..5).map(|i| tree.prefix_sum(i)).collect();
+    assert_eq!(prefix_sums, [1, 3, -4, 0, 5]);
+    assert_eq!(tree.range_sum(1, 3), -1);
+    assert_eq!(tree.range_sum(4, 4), 5);
+
+    // Same values through updates on an empty tree, compared to naive sums
+    let values: Vec<i64> = (0..100).map(|i| (i * 37 % 23) - 11).collect();
+    let mut tree = data_structures::FenwickTree::new(values.len());
+    for (index, &value) in values.iter().enumerate() {
+        tree.update(index, value);
+    }
+    for l in 0..values.len() {
+        for r in l..values.len() {
+            assert_eq!(tree.range_sum(l, r), values[l..=r].iter().sum::<i64>());
+        }
+    }
+}
+
+#[test]
+fn test_misc_are_anagrams() {
+    assert!(misc::are_anagrams("listen", "silent"));
+    assert!(!misc::are_anagrams("hello", "world"));
+    assert!(misc::are_anagrams("Listen", "SILENT"));
+    assert!(misc::are_anagrams("Dormitory", "dirty room"));
+    assert!(!misc::are_anagrams("abc", "abcc"));
+    assert!(!misc::are_anagrams("aab", "abb"));
+    assert!(misc::are_anagrams("", " "));
+    assert!(misc::are_anagrams("éte", "tÉe"));
+}
+
+#[test]
+fn test_misc_palindromes() {
+    assert!(misc::is_palindrome_str("kayak", false));
+    assert!(misc::is_palindrome_str("a", false));
+    assert!(misc::is_palindrome_str("", false));
+    assert!(!misc::is_palindrome_str("Kayak", false));
+    assert!(misc::is_palindrome_str("Kayak", true));
+    assert!(misc::is_palindrome_str(
+        "A man, a plan, a canal: Panama",
+        true
+    ));
+    assert!(!misc::is_palindrome_str("A man, a plan", true));
+
+    assert!(misc::is_palindrome_number(12321));
+    assert!(!misc::is_palindrome_number(123));
+    assert!(misc::is_palindrome_number(0));
+    assert!(misc::is_palindrome_number(7));
+    assert!(misc::is_palindrome_number(1221));
+    assert!(!misc::is_palindrome_number(10));
+    assert!(!misc::is_palindrome_number(u64::MAX));
+}
+
+#[test]
+fn test_misc_binomial_and_catalan() {
+    assert_eq!(misc::binomial(5, 2), 10);
+    assert_eq!(misc::binomial(3, 5), 0);
+    assert_eq!(misc::binomial(0, 0), 1);
+
+    // Pascal's triangle
+    let mut row: Vec<u128> = vec![1];
+    for n in 1..=60 {
+        let mut next_row = vec![1; n + 1];
+        for k in 1..n {
+            next_row[k] = row[k - 1] + row[k];
+        }
+        row = next_row;
+        let binomials: Vec<u128> = (0..=n as u64)
+            .map(|k| misc::binomial(n as u64, k))
+            .collect();
+        assert_eq!(binomials, row);
+    }
+
+    // Large values, whose naive computation would overflow
+    assert_eq!(misc::binomial(100, 50), 100891344545564193334812497256);
+    assert_eq!(misc::binomial(1_000_000, 1), 1_000_000);
+
+    let catalans: Vec<u128> = (0..10).map(misc::catalan).collect();
+    assert_eq!(catalans, [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862]);
+    assert_eq!(misc::catalan(30), 3814986502092304);
+}
+
+#[test]
+#[should_panic]
+fn test_misc_catalan_overflow() {
+    misc::catalan(u64::MAX);
+}
+
+#[test]
+fn test_sorting_variants_cycle_sort() {
+    let mut array: Vec<i32> = (0..300).collect();
+    probabilities::fisher_yates_shuffle(&mut array, 5);
+    let writes = sorting_variants::cycle_sort(&mut array);
+    assert_eq!(array, (0..300).collect::<Vec<i32>>());
+    assert!(writes <= 300);
+
+    // Elements already in place are never written
+    let mut array = [1, 2, 3, 5, 4];
+    assert_eq!(sorting_variants::cycle_sort(&mut array), 2);
+    assert_eq!(array, [1, 2, 3, 4, 5]);
+    assert_eq!(sorting_variants::cycle_sort(&mut array), 0);
+
+    let mut array = [3, 1, 3, 2, 1, 3, 0];
+    let writes = sorting_variants::cycle_sort(&mut array);
+    assert_eq!(array, [0, 1, 1, 2, 3, 3, 3]);
+    assert!(writes <= 7);
+
+    let mut array = ["pear", "apple", "fig"];
+    sorting_variants::cycle_sort(&mut array);
+    assert_eq!(array, ["apple", "fig", "pear"]);
+}
+
+#[test]
+fn test_sorting_variants_pancake_sort() {
+    let mut array: Vec<i32> = (0..100).map(|i| (i * 37) % 41).collect();
+    let original = array.clone();
+    let flips = sorting_variants::pancake_sort(&mut array);
+    assert!(sorting::is_array_sorted(&array));
+    assert!(flips.len() <= 2 * (array.len() - 1));
+
+    // Replaying the flips on the original array sorts it
+    let mut replayed = original;
+    for &size in &flips {
+        assert!((2..=replayed.len()).contains(&size));
+        replayed[..size].reverse();
+    }
+    assert_eq!(replayed, array);
+
+    let mut array = [3, 1, 2];
+    assert_eq!(sorting_variants::pancake_sort(&mut array), vec![3, 2]);
+    assert_eq!(array, [1, 2, 3]);
+    assert!(sorting_variants::pancake_sort(&mut array).is_empty());
+
+    let mut array: [i32; 0] = [];
+    assert!(sorting_variants::pancake_sort(&mut array).is_empty());
+}
+
+#[test]
+fn test_sorting_variants_permutation_parity() {
+    assert!(sorting_variants::permutation_parity(&[0, 1, 2, 3]));
+    assert!(!sorting_variants::permutation_parity(&[0, 3, 2, 1]));
+    assert!(sorting_variants::permutation_parity(&[1, 2, 0, 3]));
+    assert!(!sorting_variants::permutation_parity(&[1, 2, 3, 0]));
+    assert!(sorting_variants::permutation_parity(&[]));
+
+    // Parity of the sorting permutation of an array with 2 inversions
+    let permutation = sorting_variants::generic_indirect_selection_sort(&[2, 3, 1]);
+    assert!(sorting_variants::permutation_parity(&permutation));
+}
+
+#[test]
+#[should_panic]
+fn test_sorting_variants_permutation_parity_invalid() {
+    sorting_variants::permutation_parity(&[0, 2, 2]);
+}
+
+#[test]
+fn test_sorting_variants_apply_permutation_in_place() {
+    let mut rng = probabilities::MinstdRng::new(17);
+    for n in [0, 1, 2, 10, 257] {
+        let array: Vec<u32> = (0..n).map(|_| rng.gen() % 100).collect();
+        let mut shuffled: Vec<i32> = (0..n).collect();
+        probabilities::fisher_yates_shuffle(&mut shuffled, rng.gen());
+        let mut permutation: Vec<usize> = shuffled.iter().map(|&i| i as usize).collect();
+        let permutation_copy = permutation.clone();
+
+        let expected = sorting_variants::permute_copy_array(&array, &permutation);
+        let mut permuted = array.clone();
+        sorting_variants::apply_permutation_in_place(&mut permuted, &mut permutation);
+        assert_eq!(permuted, expected);
+        assert_eq!(permutation, permutation_copy);
+    }
+
+    // Sorting through the indirect selection sort, without copy
+    let mut array = ["pear", "apple", "fig", "banana"];
+    let mut permutation = sorting_variants::generic_indirect_selection_sort(&array);
+    sorting_variants::apply_permutation_in_place(&mut array, &mut permutation);
+    assert_eq!(array, ["apple", "banana", "fig", "pear"]);
+}
+
+#[test]
+fn test_misc_argmin_argmax() {
+    let values = [3.0, 1.0, 4.0, 1.0, 5.0];
+    assert_eq!(misc::argmin(&values), Some(1));
+    assert_eq!(misc::argmax(&values), Some(4));
+
+    assert_eq!(misc::argmin(&[2, 7, 7, 2]), Some(0));
+    assert_eq!(misc::argmax(&[2, 7, 7, 2]), Some(1));
+    assert_eq!(misc::argmin(&["b", "a", "c"]), Some(1));
+    assert_eq!(misc::argmin::<f64>(&[]), None);
+    assert_eq!(misc::argmax::<f64>(&[]), None);
+
+    // NaN never wins, even in first position
+    assert_eq!(misc::argmin(&[f64::NAN, 1.0, 0.5]), Some(2));
+    assert_eq!(misc::argmax(&[f64::NAN, 1.0, 0.5]), Some(1));
+    assert_eq!(misc::argmin(&[2.0, f64::NAN, 1.0]), Some(2));
+    assert_eq!(misc::argmax(&[f64::NAN, f64::NAN]), None);
+}
+
+#[test]
+fn test_misc_clamp_and_lerp() {
+    assert_eq!(misc::clamp(-0.5, 0., 1.), 0.);
+    assert_eq!(misc::clamp(0.25, 0., 1.), 0.25);
+    assert_eq!(misc::clamp(1.5, 0., 1.), 1.);
+    assert_eq!(misc::clamp(0., 0., 1.), 0.);
+    assert_eq!(misc::clamp(1., 0., 1.), 1.);
+    assert_eq!(misc::clamp(12, 3, 7), 7);
+    assert_eq!(misc::clamp(5, 5, 5), 5);
+
+    assert_eq!(misc::lerp(2., 4., 0.), 2.);
+    assert_eq!(misc::lerp(2., 4., 0.5), 3.);
+    assert_eq!(misc::lerp(2., 4., 1.), 4.);
+    assert_eq!(misc::lerp(2., 4., 2.), 6.);
+    assert_eq!(misc::lerp(4., -4., 0.25), 2.);
+}
+
+#[test]
+#[should_panic]
+fn test_misc_clamp_invalid_range() {
+    misc::clamp(0, 2, 1);
+}
+
+#[test]
+fn test_files_csv() {
+    assert_eq!(
+        files::parse_csv("a,\"b,c\",d", ','),
+        vec![vec!["a", "b,c", "d"]]
+    );
+    assert_eq!(
+        files::parse_csv("x;y\r\n1;\"say \"\"hi\"\"\"\n;\n", ';'),
+        vec![vec!["x", "y"], vec!["1", "say \"hi\""], vec!["", ""]]
+    );
+    assert!(files::parse_csv("", ',').is_empty());
+
+    // A last row made of a single quoted empty field is kept
+    assert_eq!(files::parse_csv("\"\"", ','), vec![vec![""]]);
+    assert_eq!(files::parse_csv("a\n\"\"", ','), vec![vec!["a"], vec![""]]);
+
+    // Round trip, including quotes and line breaks inside fields
+    let rows: Vec<Vec<String>> = vec![
+        vec!["name", "comment", "value"],
+        vec!["a", "b,c", "1"],
+        vec!["quote", "He said \"no\"", ""],
+        vec!["multi", "line 1\nline 2", "3"],
+    ]
+    .into_iter()
+    .map(|row| row.into_iter().map(String::from).collect())
+    .collect();
+    let content = files::write_csv(&rows, ',');
+    assert_eq!(files::parse_csv(&content, ','), rows);
+    assert!(content.starts_with("name,comment,value\na,\"b,c\",1\n"));
+}
+
+#[test]
+fn test_files_head_tail() {
+    let file_path = std::env::temp_dir()
+        .join("rust_algorithms_test_head_tail.txt")
+        .to_string_lossy()
+        .to_string();
+    let lines: Vec<String> = (1..=10).map(|i| format!("line {}", i)).collect();
+    files::write_text_file_lines(&file_path, &lines);
+
+    assert_eq!(files::head(&file_path, 3), lines[..3]);
+    assert_eq!(files::tail(&file_path, 3), lines[7..]);
+    assert_eq!(files::head(&file_path, 20), lines);
+    assert_eq!(files::tail(&file_path, 20), lines);
+    assert!(files::head(&file_path, 0).is_empty());
+    assert!(files::tail(&file_path, 0).is_empty());
+
+    std::fs::remove_file(&file_path).unwrap();
+}
+
+#[test]
+fn test_files_write_text_file_atomic() {
+    let file_path = std::env::temp_dir()
+        .join("rust_algorithms_test_atomic.txt")
+        .to_string_lossy()
+        .to_string();
+    let content = "key=value\n".repeat(1000);
+    files::write_text_file_atomic(&file_path, &content).unwrap();
+    assert_eq!(files::read_text_file(&file_path), content);
+
+    // Replacing an existing file
+    let content = String::from("key=other_value\n");
+    files::write_text_file_atomic(&file_path, &content).unwrap();
+    assert_eq!(files::read_text_file(&file_path), content);
+
+    // No temporary file is left behind
+    let temp_file_path = format!("{}.tmp.{}", file_path, std::process::id());
+    assert!(!files::test_file_existence(&temp_file_path));
+
+    std::fs::remove_file(&file_path).unwrap();
+
+    // Missing directory
+    let file_path = std::env::temp_dir()
+        .join("rust_algorithms_missing_directory")
+        .join("file.txt")
+        .to_string_lossy()
+        .to_string();
+    assert!(files::write_text_file_atomic(&file_path, &content).is_err());
+}
+
+#[test]
+fn test_data_structures_binary_search_tree() {
+    let mut tree = data_structures::BinarySearchTree::new();
+    assert!(tree.in_order().is_empty());
+    for value in [5, 3, 8, 1, 4] {
+        assert!(tree.insert(value));
+    }
+    assert!(!tree.insert(3));
+    assert_eq!(tree.len(), 5);
+
+    assert!(tree.contains(&4));
+    assert!(!tree.contains(&2));
+    assert_eq!(tree.in_order(), vec![&1, &3, &4, &5, &8]);
+
+    // Sorted insertion order (degenerate tree)
+    let mut tree = data_structures::BinarySearchTree::default();
+    for value in 0..1000 {
+        tree.insert(value);
+    }
+    let expected: Vec<i32> = (0..1000).collect();
+    assert_eq!(tree.in_order(), expected.iter().collect::<Vec<&i32>>());
+
+    // Deep degenerate tree: formatting and dropping must not overflow the stack
+    let mut tree = data_structures::BinarySearchTree::new();
+    for value in 0..20_000 {
+        tree.insert(value);
+    }
+    assert_eq!(tree.len(), 20_000);
+    assert!(format!("{:?}", tree).ends_with("19998, 19999}"));
+    drop(tree);
+
+    let mut tree = data_structures::BinarySearchTree::new();
+    for word in ["pear", "apple", "fig"] {
+        tree.insert(word.to_string());
+    }
+    assert_eq!(tree.in_order(), vec!["apple", "fig", "pear"]);
+}
+
+#[test]
+fn test_data_structures_linked_list() {
+    let mut list = data_structures::LinkedList::new();
+    assert!(list.is_empty());
+    list.reverse();
+    assert_eq!(list.pop_front(), None);
+
+    for value in [3, 2, 1] {
+        list.push_front(value);
+    }
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.peek_front(), Some(&1));
+
+    list.reverse();
+    assert_eq!(list.len(), 3);
+    let mut popped = Vec::new();
+    while let Some(value) = list.pop_front() {
+        popped.push(value);
+    }
+    assert_eq!(popped, [3, 2, 1]);
+    assert_eq!(list.len(), 0);
+
+    // Long lists are reversed and dropped without recursion
+    let mut list = data_structures::LinkedList::default();
+    for value in 0..1_000_000 {
+        list.push_front(value);
+    }
+    list.reverse();
+    assert_eq!(list.peek_front(), Some(&0));
+    assert_eq!(list.len(), 1_000_000);
+}
+
+#[test]
+fn test_data_structures_ring_buffer() {
+    let mut buffer = data_structures::RingBuffer::new(3);
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.push(1), None);
+    assert_eq!(buffer.push(2), None);
+    assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    assert_eq!(buffer.push(3), None);
+    assert!(buffer.is_full());
+
+    // Wraparound: the oldest items are overwritten
+    assert_eq!(buffer.push(4), Some(1));
+    assert_eq!(buffer.push(5), Some(2));
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&3, &4, &5]);
+
+    // Sliding window sum over a stream
+    let mut window = data_structures::RingBuffer::new(4);
+    let mut window_sum = 0;
+    for value in 1..=100 {
+        window_sum += value;
+        if let Some(evicted) = window.push(value) {
+            window_sum -= evicted;
+        }
+        assert_eq!(window_sum, window.iter().sum::<i32>());
+    }
+    assert_eq!(window_sum, 97 + 98 + 99 + 100);
+}
+
+#[test]
+fn test_sorting_variants_median_fast() {
+    assert_eq!(sorting_variants::median_fast(&[]), None);
+    assert_eq!(sorting_variants::median_fast(&[7.5]), Some(7.5));
+    assert_eq!(sorting_variants::median_fast(&[3., 1., 2.]), Some(2.));
+    assert_eq!(sorting_variants::median_fast(&[4., 1., 3., 2.]), Some(2.5));
+
+    // Compare with a sort-based median, for both parities
+    let mut rng = probabilities::MinstdRng::new(314);
+    for n in [1, 2, 9, 10, 255, 256] {
+        let array: Vec<f64> = (0..n).map(|_| (rng.gen() % 50) as f64 - 25.).collect();
+        let mut sorted_array = array.clone();
+        sorted_array.sort_by(f64::total_cmp);
+        let expected = if n % 2 == 1 {
+            sorted_array[n / 2]
+        } else {
+            (sorted_array[n / 2 - 1] + sorted_array[n / 2]) / 2.
+        };
+        assert_eq!(sorting_variants::median_fast(&array), Some(expected));
+    }
+}
+
+#[test]
+fn test_graphs_mazes_solve_maze_stats() {
+    let maze_file = std::env::temp_dir()
+        .join("rust_algorithms_test_maze.txt")
+        .to_string_lossy()
+        .to_string();
+    let solution_file = std::env::temp_dir()
+        .join("rust_algorithms_test_maze_solution.txt")
+        .to_string_lossy()
+        .to_string();
+
+    // Shortest path: 2 steps down, 5 steps right (or 2 right, 2 down, 3 right)
+    let maze_layout: Vec<String> = [
+        "|||||||||",
+        "|@  |   |",
+        "| | | | |",
+        "|     $ |",
+        "|||||||||",
+    ]
+    .iter()
+    .map(|line| line.to_string())
+    .collect();
+    files::write_text_file_lines(&maze_file, &maze_layout);
+    let stats = graphs_mazes::solve_maze(maze_file.clone(), solution_file.clone(), false);
+    assert!(stats.solved);
+    assert_eq!(stats.path_length, 7);
+    assert_eq!(stats.steps, 7);
+    assert!(stats.nodes_expanded > 0);
+    assert!(files::test_file_existence(&solution_file));
+
+    // Unreachable end
+    let maze_layout: Vec<String> = ["|||||", "|@|$|", "|||||"]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    files::write_text_file_lines(&maze_file, &maze_layout);
+    let stats = graphs_mazes::solve_maze(maze_file.clone(), solution_file.clone(), false);
+    assert!(!stats.solved);
+    assert_eq!(stats.path_length, 0);
+    assert_eq!(stats.steps, 0);
+    assert_eq!(stats.nodes_expanded, 1);
+
+    std::fs::remove_file(&maze_file).unwrap();
+    std::fs::remove_file(&solution_file).unwrap();
+}
+
+#[test]
+fn test_graphs_mazes_nearest_pair() {
+    // Two entrances and two exits: the closest pair is (@ right, $ right), at distance 2
+    let maze_layout: Vec<String> = ["|||||||||||", "|@   $  @ |", "|      $  |", "|||||||||||"]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    let maze = graphs_mazes::Maze::new(&maze_layout);
+    let (start, end, distance) =
+        graphs_mazes::solve_nearest_pair(&maze, maze.start_positions(), maze.end_positions())
+            .unwrap();
+    assert_eq!(graphs_mazes::Maze::position_to_coordinates(start), (1, 8));
+    assert_eq!(graphs_mazes::Maze::position_to_coordinates(end), (2, 7));
+    assert_eq!(distance, 2);
+
+    // Weighted graph: the nearest exit is not the one with the fewest edges
+    let mut graph: graphs_mazes::Graph<char, u32> = graphs_mazes::Graph::new();
+    graph.add_edge('s', 'x', 10);
+    graph.add_edge('t', 'a', 1);
+    graph.add_edge('a', 'y', 2);
+    assert_eq!(
+        graphs_mazes::solve_nearest_pair(&graph, vec!['s', 't'], vec!['x', 'y']),
+        Some(('t', 'y', 3))
+    );
+    assert_eq!(
+        graphs_mazes::solve_nearest_pair(&graph, vec!['s'], vec!['y']),
+        None
+    );
+}
+
+#[test]
+fn test_graphs_mazes_coord_encoding() {
+    let coord = graphs_mazes::Coord::new(3, 7);
+    assert_eq!(graphs_mazes::Coord::from(u64::from(coord)), coord);
+    assert_eq!(
+        u64::from(coord),
+        graphs_mazes::Maze::coordinates_to_position(3, 7)
+    );
+
+    // Same shortest path lengths with both vertex encodings
+    let maze_directory = "divers/labyrinthes".to_string();
+    for maze_file in files::list_directory(&maze_directory) {
+        if maze_file.contains("solution") {
+            continue;
+        }
+        let maze_layout: Vec<String> = files::read_text_file_lines(&maze_file, None);
+        let maze = graphs_mazes::Maze::new(&maze_layout);
+
+        let result =
+            graphs_mazes::solve_dijkstra(&maze, maze.start_positions(), maze.end_positions());
+        let coord_result =
+            graphs_mazes::solve_dijkstra(&maze, maze.start_coords(), maze.end_coords());
+
+        let distance: Option<u64> = result.reached_end.map(|pos| result.distances[&pos]);
+        let coord_distance: Option<u64> = coord_result
+            .reached_end
+            .map(|coord| coord_result.distances[&coord]);
+        assert_eq!(distance, coord_distance, "maze: {}", maze_file);
+    }
+}
+
+#[test]
+fn test_graphs_mazes_dijkstra_zero_trait() {
+    // Float distances
+    let mut graph: graphs_mazes::Graph<char, f64> = graphs_mazes::Graph::new();
+    graph.add_edge('a', 'b', 0.5);
+    graph.add_edge('b', 'c', 0.25);
+    graph.add_edge('a', 'c', 1.);
+    let result = graphs_mazes::solve_dijkstra(&graph, vec!['a'], vec!['c']);
+    assert_eq!(result.reached_end, Some('c'));
+    assert_eq!(result.distances[&'c'], 0.75);
+
+    // Custom distance type: only Zero is required to provide the initial distance
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    struct Meters(u32);
+    impl std::ops::Add for Meters {
+        type Output = Meters;
+        fn add(self, other: Meters) -> Meters {
+            Meters(self.0 + other.0)
+        }
+    }
+    impl graphs_mazes::Zero for Meters {
+        fn zero() -> Self {
+            Meters(0)
+        }
+    }
+
+    let mut graph: graphs_mazes::Graph<u8, Meters> = graphs_mazes::Graph::new();
+    graph.add_edge(0, 1, Meters(100));
+    graph.add_edge(1, 2, Meters(50));
+    assert_eq!(
+        graphs_mazes::solve_dijkstra_bidirectional(&graph, 0, 2),
+        Some((Meters(150), vec![0, 1, 2]))
+    );
+    assert_eq!(
+        graphs_mazes::solve_dijkstra_bidirectional(&graph, 2, 2),
+        Some((Meters(0), vec![2]))
+    );
+}
+
+#[test]
+fn test_graphs_mazes_dijkstra_result() {
+    let maze_layout: Vec<String> = ["|||||||", "|@  | |", "| | | |", "|   $ |", "|||||||"]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    let maze = graphs_mazes::Maze::new(&maze_layout);
+    let result = graphs_mazes::solve_dijkstra(&maze, maze.start_coords(), maze.end_coords());
+
+    let end = result.reached_end.unwrap();
+    assert_eq!(end, graphs_mazes::Coord::new(3, 4));
+    assert_eq!(result.distance_to(&end), Some(&5));
+
+    let path = result.path_to(&end);
+    assert_eq!(path.len(), 6);
+    assert_eq!(path[0], graphs_mazes::Coord::new(1, 1));
+    assert_eq!(path[5], end);
+    for step in path.windows(2) {
+        let row_delta = step[0].row.abs_diff(step[1].row);
+        let col_delta = step[0].col.abs_diff(step[1].col);
+        assert_eq!(row_delta + col_delta, 1);
+    }
+
+    // Cell farther than the end, hence not processed before the search stopped
+    let far_cell = graphs_mazes::Coord::new(1, 5);
+    assert_eq!(result.distance_to(&far_cell), None);
+    assert!(result.path_to(&far_cell).is_empty());
+}
+
+#[test]
+fn test_probabilities_mean_f64_and_int() {
+    assert_eq!(probabilities::mean_int(&[1, 2]), Some(1.5));
+    assert_eq!(probabilities::mean_int(&[-3, 4, 5]), Some(2.));
+    assert_eq!(
+        probabilities::mean_int(&[i64::MAX, i64::MAX]),
+        Some(i64::MAX as f64)
+    );
+    assert_eq!(probabilities::mean_int(&[]), None);
+
+    assert_eq!(probabilities::mean_f64(&[1., 2., 3., 4.]), Some(2.5));
+    assert_eq!(probabilities::mean_f64(&[]), None);
+
+    // Same result as the generic version on floats
+    let values = [0.5, 1.25, -2., 8.];
+    assert_eq!(
+        probabilities::mean_f64(&values),
+        probabilities::mean(&values)
+    );
+}
+
+#[test]
+fn test_probabilities_geometric_and_harmonic_means() {
+    let tolerance = 1e-12;
+    assert!((probabilities::geometric_mean(&[1., 4.]).unwrap() - 2.).abs() < tolerance);
+    assert!((probabilities::geometric_mean(&[2., 8., 4.]).unwrap() - 4.).abs() < tolerance);
+    assert!((probabilities::harmonic_mean(&[1., 4.]).unwrap() - 1.6).abs() < tolerance);
+    assert!((probabilities::harmonic_mean(&[3., 6.]).unwrap() - 4.).abs() < tolerance);
+    assert_eq!(probabilities::geometric_mean(&[]), None);
+    assert_eq!(probabilities::harmonic_mean(&[]), None);
+
+    // The product 1e200 * 1e200 overflows, but not the geometric mean
+    let geometric_mean = probabilities::geometric_mean(&[1e200, 1e200]).unwrap();
+    assert!((geometric_mean / 1e200 - 1.).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic]
+fn test_probabilities_geometric_mean_non_positive() {
+    probabilities::geometric_mean(&[1., 0.]);
+}
+
+#[test]
+#[should_panic]
+fn test_probabilities_harmonic_mean_zero() {
+    probabilities::harmonic_mean(&[1., 0.]);
+}
+
+#[test]
+#[should_panic]
+fn test_probabilities_harmonic_mean_mixed_signs() {
+    probabilities::harmonic_mean(&[1., -1.]);
+}
+
+#[test]
+fn test_probabilities_chi_square_statistic() {
+    assert_eq!(
+        probabilities::chi_square_statistic(&[10, 20, 30], &[10., 20., 30.]),
+        Some(0.)
+    );
+    assert_eq!(
+        probabilities::chi_square_statistic(&[60, 0], &[30., 30.]),
+        Some(60.)
+    );
+    assert_eq!(probabilities::chi_square_statistic(&[1, 2], &[1.]), None);
+    assert_eq!(
+        probabilities::chi_square_statistic(&[1, 2], &[3., 0.]),
+        None
+    );
+
+    // MinstdRng output is uniform: the statistic stays below the critical value
+    // for 9 degrees of freedom at the 0.1% level (27.88)
+    let mut rng = probabilities::MinstdRng::new(1);
+    let data: Vec<f64> = (0..10000).map(|_| rng.gen_f64()).collect();
+    let observed = probabilities::histogram(&data, 10, 0., 1.);
+    let statistic = probabilities::chi_square_statistic(&observed, &[1000.; 10]).unwrap();
+    assert!(statistic < 27.88);
+
+    // Skewed data: squared values are far from uniform
+    let squared_data: Vec<f64> = data.iter().map(|x| x * x).collect();
+    let observed = probabilities::histogram(&squared_data, 10, 0., 1.);
+    let statistic = probabilities::chi_square_statistic(&observed, &[1000.; 10]).unwrap();
+    assert!(statistic > 1000.);
+}
+
+#[test]
+fn test_sorting_variants_sort_networks() {
+    // All the permutations of 4 elements
+    for permutation in misc::permutations(&[0, 1, 2, 3]) {
+        let mut array: [i32; 4] = permutation.try_into().unwrap();
+        sorting_variants::sort_network_4(&mut array);
+        assert_eq!(array, [0, 1, 2, 3]);
+    }
+
+    // All the permutations of 8 elements
+    for permutation in misc::permutations(&[0, 1, 2, 3, 4, 5, 6, 7]) {
+        let mut array: [i32; 8] = permutation.try_into().unwrap();
+        sorting_variants::sort_network_8(&mut array);
+        assert_eq!(array, [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    // Duplicates, 8 queens rows
+    let mut array = [2, 2, 1, 1, 3, 3, 0, 0];
+    sorting_variants::sort_network_8(&mut array);
+    assert_eq!(array, [0, 0, 1, 1, 2, 2, 3, 3]);
+    for solution in misc::solve_8_queens_problem() {
+        let mut rows: [usize; 8] = solution;
+        sorting_variants::sort_network_8(&mut rows);
+        assert_eq!(rows, [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+}
+
+#[test]
+fn test_sorting_variants_tim_sort() {
+    let mut rng = probabilities::MinstdRng::new(2718);
+    for n in [0, 1, 2, 31, 64, 65, 1000, 5000] {
+        let array: Vec<i32> = (0..n).map(|_| (rng.gen() % 100) as i32).collect();
+        let mut expected = array.clone();
+        sorting::heap_sort(&mut expected);
+        let mut sorted = array.clone();
+        sorting_variants::tim_sort(&mut sorted);
+        assert_eq!(sorted, expected);
+    }
+
+    // Stability: each element gets its own counter, whose position in `counters`
+    // identifies the element; equal keys must keep their original order
+    let keys: Vec<i32> = (0..3000).map(|_| (rng.gen() % 10) as i32).collect();
+    let counters: Vec<Cell<usize>> = keys.iter().map(|_| Cell::new(0)).collect();
+    let mut array: Vec<CountedCmp> = keys
+        .iter()
+        .zip(&counters)
+        .map(|(&value, counter)| CountedCmp { value, counter })
+        .collect();
+    sorting_variants::tim_sort(&mut array);
+    let original_index = |elem: &CountedCmp| {
+        counters
+            .iter()
+            .position(|c| std::ptr::eq(c, elem.counter))
+            .unwrap()
+    };
+    assert!(array.windows(2).all(|pair| pair[0].value < pair[1].value
+        || (pair[0].value == pair[1].value
+            && original_index(&pair[0]) < original_index(&pair[1]))));
+
+    // Nearly sorted data (sorted batches appended together) needs far fewer comparisons
+    let mut values: Vec<i32> = Vec::new();
+    for batch in 0..4 {
+        values.extend((0..2500).map(|i| 4 * i + batch));
+    }
+    let nearly_sorted_counter = Cell::new(0);
+    let mut array = counted_array(&values, &nearly_sorted_counter);
+    sorting_variants::tim_sort(&mut array);
+    assert!(array.windows(2).all(|pair| pair[0].value <= pair[1].value));
+
+    let mut random_values: Vec<i32> = (0..10000).collect();
+    probabilities::fisher_yates_shuffle(&mut random_values, 1);
+    let random_counter = Cell::new(0);
+    let mut array = counted_array(&random_values, &random_counter);
+    sorting_variants::tim_sort(&mut array);
+    assert!(array.windows(2).all(|pair| pair[0].value <= pair[1].value));
+
+    assert!(5 * nearly_sorted_counter.get() < random_counter.get());
+}
+
+#[test]
+fn test_sorting_variants_stooge_sort() {
+    let mut rng = probabilities::MinstdRng::new(31);
+    // Stooge sort is too slow for large arrays
+    for n in 0..40 {
+        let array: Vec<i32> = (0..n).map(|_| (rng.gen() % 20) as i32).collect();
+        let mut expected = array.clone();
+        sorting::heap_sort(&mut expected);
+        let mut sorted = array;
+        sorting_variants::stooge_sort(&mut sorted);
+        assert_eq!(sorted, expected);
+    }
+}
+
+#[test]
+fn test_sorting_sort_order() {
+    use sorting::SortOrder;
+    assert_eq!(sorting::sort_order(&[1, 2, 3]), SortOrder::Ascending);
+    assert_eq!(sorting::sort_order(&[3, 2, 1]), SortOrder::Descending);
+    assert_eq!(sorting::sort_order(&[2, 2, 2]), SortOrder::Constant);
+    assert_eq!(sorting::sort_order(&[1, 3, 2]), SortOrder::Unsorted);
+
+    // Equal neighbours do not change the direction
+    assert_eq!(sorting::sort_order(&[1, 1, 2, 2]), SortOrder::Ascending);
+    assert_eq!(sorting::sort_order(&[5, 5, 4]), SortOrder::Descending);
+    assert_eq!(sorting::sort_order(&[2, 2, 1, 3]), SortOrder::Unsorted);
+    assert_eq!(sorting::sort_order::<i32>(&[]), SortOrder::Constant);
+    assert_eq!(sorting::sort_order(&[7]), SortOrder::Constant);
+}
+
+#[test]
+fn test_sorting_count_inversions() {
+    assert_eq!(sorting::count_inversions(&[2, 4, 1, 3, 5]), 3);
+    assert_eq!(sorting::count_inversions(&[1, 2, 3, 4, 5]), 0);
+    assert_eq!(sorting::count_inversions::<i32>(&[]), 0);
+    // Equal elements are not inversions
+    assert_eq!(sorting::count_inversions(&[2, 2, 2]), 0);
+    let reversed: Vec<i32> = (0..100).rev().collect();
+    assert_eq!(sorting::count_inversions(&reversed), 100 * 99 / 2);
+
+    // Comparison with the quadratic definition
+    let mut rng = probabilities::MinstdRng::new(17);
+    let array: Vec<i32> = (0..300).map(|_| (rng.gen() % 50) as i32).collect();
+    let mut expected = 0;
+    for i in 0..array.len() {
+        for j in (i + 1)..array.len() {
+            if array[i] > array[j] {
+                expected += 1;
+            }
+        }
+    }
+    assert_eq!(sorting::count_inversions(&array), expected);
+}
+
+#[test]
+fn test_sorting_no_stdout() {
+    // The sorts run in a child process of this test binary, whose stdout is not captured
+    const CHILD_ENV_VAR: &str = "RUST_ALGORITHMS_NO_STDOUT_CHILD";
+    if std::env::var_os(CHILD_ENV_VAR).is_some() {
+        let values = [5, 2, 4, 6, 1, 3];
+        println!("<begin>");
+        sorting::insertion_sort(&mut values.clone());
+        sorting::selection_sort(&mut values.clone());
+        sorting::merge_sort(&mut values.clone());
+        sorting::heap_sort(&mut values.clone());
+        sorting_variants::generic_insertion_sort(&mut values.clone());
+        sorting_variants::generic_indirect_selection_sort(&values);
+        sorting_variants::optimized_merge_sort(&mut values.clone(), None, None, None);
+        classics::linear_search(&values, 4);
+        classics::generic_linear_search(&values, 4);
+        println!("<end>");
+        return;
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "tests::test_sorting_no_stdout",
+            "--exact",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env(CHILD_ENV_VAR, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let begin = stdout.find("<begin>\n").unwrap() + "<begin>\n".len();
+    let end = stdout.find("<end>").unwrap();
+    assert_eq!(&stdout[begin..end], "");
+}
+
+#[test]
+fn test_misc_isqrt_ilog2() {
+    for m in [0u64, 1, 2, 3, 10, 1000, 1 << 20, 1_000_003, 4_294_967_295] {
+        assert_eq!(misc::isqrt(m * m), m);
+        if m > 0 {
+            assert_eq!(misc::isqrt(m * m - 1), m - 1);
+        }
+        assert_eq!(misc::isqrt(m * m + 2 * m), m);
+    }
+    assert_eq!(misc::isqrt(1 << 40), 1 << 20);
+    assert_eq!(misc::isqrt((1 << 40) - 1), (1 << 20) - 1);
+    assert_eq!(misc::isqrt(u64::MAX), 4_294_967_295);
+
+    assert_eq!(misc::ilog2(1), 0);
+    assert_eq!(misc::ilog2(2), 1);
+    assert_eq!(misc::ilog2(3), 1);
+    assert_eq!(misc::ilog2(1 << 40), 40);
+    assert_eq!(misc::ilog2((1 << 40) - 1), 39);
+    assert_eq!(misc::ilog2(u64::MAX), 63);
+}
+
+#[test]
+fn test_misc_find_primes_square_boundaries() {
+    // Squares of primes must not be reported as prime: their only odd divisor below
+    // the root bound is the root itself
+    for p in [3usize, 7, 99_991, 1_000_003] {
+        let square = p * p;
+        assert_eq!(misc::find_primes(p, p + 1), vec![p]);
+        assert!(misc::find_primes(square, square + 1).is_empty());
+        assert!(misc::find_primes(square - 2, square + 3)
+            .iter()
+            .all(|&q| q != square));
+    }
+
+    assert_eq!(
+        misc::find_primes(0, 200_000),
+        misc::find_primes_segmented(0, 200_000, 10_000)
+    );
+}
+
+#[test]
+fn test_graphs_mazes_weighted_terrain() {
+    // Crossing the '9' cell costs more than the detour through the '1' cells
+    let layout: Vec<String> = ["|||||||", "|@ 9 $|", "| 111 |", "|||||||"]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    // Without opting in, digits are walls and the end is unreachable
+    let plain_maze = graphs_mazes::Maze::new(&layout);
+    assert!(!plain_maze.is_weighted());
+    assert_eq!(plain_maze.cell_cost(graphs_mazes::Coord::new(1, 3)), None);
+    let result = graphs_mazes::solve_dijkstra(
+        &plain_maze,
+        plain_maze.start_coords(),
+        plain_maze.end_coords(),
+    );
+    assert!(result.reached_end.is_none());
+
+    let maze = graphs_mazes::Maze::new_weighted(&layout);
+    assert!(maze.is_weighted());
+    assert_eq!(maze.cell_cost(graphs_mazes::Coord::new(1, 3)), Some(9));
+    assert_eq!(maze.cell_cost(graphs_mazes::Coord::new(1, 2)), Some(1));
+    assert_eq!(maze.cell_cost(graphs_mazes::Coord::new(0, 0)), None);
+
+    let result = graphs_mazes::solve_dijkstra(&maze, maze.start_coords(), maze.end_coords());
+    let end = result.reached_end.unwrap();
+    let path = result.path_to(&end);
+    // The goal distance is the sum of the costs of the cells entered along the path
+    let path_cost: u64 = path[1..]
+        .iter()
+        .map(|&coord| maze.cell_cost(coord).unwrap())
+        .sum();
+    assert_eq!(*result.distance_to(&end).unwrap(), path_cost);
+    assert_eq!(path_cost, 6);
+
+    let maze_file = std::env::temp_dir()
+        .join("rust_algorithms_test_weighted_maze.txt")
+        .to_string_lossy()
+        .to_string();
+    let solution_file = std::env::temp_dir()
+        .join("rust_algorithms_test_weighted_maze_solution.txt")
+        .to_string_lossy()
+        .to_string();
+    files::write_text_file_lines(&maze_file, &layout);
+    let stats = graphs_mazes::solve_weighted_maze(maze_file.clone(), solution_file.clone(), false);
+    // On weighted terrain, the path length is the cost, distinct from the step count
+    assert_eq!(stats.path_length, path_cost);
+    assert_eq!(stats.steps, path.len() as u64 - 1);
+    std::fs::remove_file(&maze_file).unwrap();
+    std::fs::remove_file(&solution_file).unwrap();
+
+    // A weighted cell explored by the solver but avoided by the path does not count
+    let layout: Vec<String> = ["||||||", "|@  $|", "|2   |", "||||||"]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    let maze = graphs_mazes::Maze::new_weighted(&layout);
+    let result = graphs_mazes::solve_dijkstra(&maze, maze.start_coords(), maze.end_coords());
+    assert!(result
+        .distance_to(&graphs_mazes::Coord::new(2, 1))
+        .is_some());
+    assert!(!graphs_mazes::crosses_weighted_terrain(
+        &maze,
+        &[(2, 1), (3, 1)]
+    ));
+    assert!(graphs_mazes::crosses_weighted_terrain(
+        &maze,
+        &[(1, 2), (2, 2)]
+    ));
+
+    // Forced crossing of an expensive cell: the path is shaded in red there
+    let layout: Vec<String> = vec!["@9 $".to_string()];
+    let maze = graphs_mazes::Maze::new_weighted(&layout);
+    let solution = graphs_mazes::render_solution(&layout, &[], &[(1, 0), (2, 0)]);
+    let colored = graphs_mazes::colorize_by_cost(&solution, &maze);
+    assert_eq!(
+        colored[0],
+        "\x1b[94m@\x1b[0m\x1b[91mx\x1b[0m\x1b[93mx\x1b[0m\x1b[92m$\x1b[0m"
+    );
+}
+
+#[test]
+fn test_fractals_write_fractal_csv() {
+    let file_path = std::env::temp_dir()
+        .join("rust_algorithms_test_fractal.csv")
+        .to_string_lossy()
+        .to_string();
+
+    let params = fractals::FractalParams {
+        width: 8,
+        height: 5,
+        ..fractals::FractalParams::new(0.3, 0.5)
+    };
+    let pixels = fractals::render_fractal(params);
+    fractals::write_fractal_csv(&file_path, 8, 5, &pixels);
+
+    let rows = files::parse_csv(&files::read_text_file(&file_path), ',');
+    assert_eq!(rows.len(), 5);
+    assert!(rows.iter().all(|row| row.len() == 8));
+    let values: Vec<f64> = rows
+        .iter()
+        .flatten()
+        .map(|value| value.parse::<f64>().unwrap())
+        .collect();
+    assert_eq!(values.len(), pixels.len());
+    for (value, pixel) in values.iter().zip(&pixels) {
+        assert!((value - pixel).abs() < 1e-12);
+    }
+
+    std::fs::remove_file(&file_path).unwrap();
+}
+
+#[test]
+fn test_graphs_mazes_display_without_color() {
+    let layout: Vec<String> = vec!["@9 $".to_string()];
+    let maze = graphs_mazes::Maze::new_weighted(&layout);
+    let solution = graphs_mazes::render_solution(&layout, &[], &[(1, 0), (2, 0)]);
+
+    for weighted_terrain in [false, true] {
+        let plain =
+            graphs_mazes::format_solution_display(&solution, &maze, weighted_terrain, false);
+        assert_eq!(plain, solution);
+        assert!(plain.iter().all(|line| !line.contains("\x1b[")));
+
+        let colored =
+            graphs_mazes::format_solution_display(&solution, &maze, weighted_terrain, true);
+        assert!(colored[0].contains("\x1b["));
+    }
+}
+
+#[test]
+fn test_numerical_methods_solve_linear_system() {
+    // Solution (1, -2, 3); the zero top-left entry requires pivoting
+    let a = numerical_methods::Matrix::from_vec(3, 3, vec![0., 2., 1., 1., -1., 2., 3., 1., -1.]);
+    let b = [-1., 9., -2.];
+    let x = numerical_methods::solve_linear_system(&a, &b).unwrap();
+    for (value, expected) in x.iter().zip([1., -2., 3.]) {
+        assert!((value - expected).abs() < 1e-12);
+    }
+
+    // Singular system: the third row is the sum of the first two
+    let singular =
+        numerical_methods::Matrix::from_vec(3, 3, vec![1., 2., 3., 4., 5., 6., 5., 7., 9.]);
+    assert_eq!(
+        numerical_methods::solve_linear_system(&singular, &[1., 2., 3.]),
+        None
+    );
+    let zero: numerical_methods::Matrix<f64> = numerical_methods::Matrix::new(2, 2);
+    assert_eq!(
+        numerical_methods::solve_linear_system(&zero, &[0., 0.]),
+        None
+    );
+}
+
+#[test]
+fn test_numerical_methods_polynomial_bisection() {
+    let p = numerical_methods::Polynomial::new(vec![1., -3., 0., 2.]); // 2x^3 - 3x + 1
+    for x in [-2., -0.5, 0., 0.3, 1., 4.] {
+        let expected = 2. * x * x * x - 3. * x + 1.;
+        assert!((p.evaluate(x) - expected).abs() < 1e-12);
+    }
+    assert_eq!(
+        p.derivative(),
+        numerical_methods::Polynomial::new(vec![-3., 0., 6.])
+    );
+    assert_eq!(numerical_methods::Polynomial::new(vec![]).evaluate(3.), 0.);
+    assert!(numerical_methods::Polynomial::new(vec![5.])
+        .derivative()
+        .coefficients()
+        .is_empty());
+
+    let square_minus_2 = numerical_methods::Polynomial::new(vec![-2., 0., 1.]);
+    let root =
+        numerical_methods::find_root_bisection(|x| square_minus_2.evaluate(x), 0., 2., 1e-10)
+            .unwrap();
+    assert!((root - std::f64::consts::SQRT_2).abs() < 1e-10);
+
+    // Reversed bounds, exact root at a bound, no sign change
+    let root = numerical_methods::find_root_bisection(|x| x - 0.5, 1., 0., 1e-12).unwrap();
+    assert!((root - 0.5).abs() < 1e-12);
+    assert_eq!(
+        numerical_methods::find_root_bisection(|x| x, 0., 1., 1e-12),
+        Some(0.)
+    );
+    assert_eq!(
+        numerical_methods::find_root_bisection(|x| x * x + 1., -1., 1., 1e-12),
+        None
+    );
+}
+
+#[test]
+fn test_probabilities_shuffle_position_distribution() {
+    let (n, trials) = (5, 50_000);
+    // Seeded and generator-based shuffles agree for the same seed
+    let mut seeded: Vec<i32> = (0..20).collect();
+    let mut with_rng = seeded.clone();
+    probabilities::fisher_yates_shuffle(&mut seeded, 4242);
+    probabilities::fisher_yates_shuffle_with_rng(
+        &mut with_rng,
+        &mut probabilities::MinstdRng::new(4242),
+    );
+    assert_eq!(seeded, with_rng);
+
+    let counts = probabilities::shuffle_position_distribution(n, trials, 4242);
+    assert_eq!(counts.len(), n);
+
+    // Each element lands exactly once per shuffle, and each position is filled once
+    for row in &counts {
+        assert_eq!(row.iter().sum::<usize>(), trials);
+    }
+    for position in 0..n {
+        assert_eq!(
+            counts.iter().map(|row| row[position]).sum::<usize>(),
+            trials
+        );
+    }
+
+    // Unbiased shuffle: every cell within 5% of trials / n (about 5 standard deviations)
+    let expected = trials / n;
+    for row in &counts {
+        for &count in row {
+            assert!(count.abs_diff(expected) < expected / 20, "count {}", count);
+        }
+    }
+}
+
+#[test]
+fn test_probabilities_seed_from_str() {
+    let seed = probabilities::seed_from_str("experiment-1");
+    assert_eq!(seed, probabilities::seed_from_str("experiment-1"));
+    assert_ne!(seed, probabilities::seed_from_str("experiment-2"));
+    assert_ne!(
+        probabilities::seed_from_str("ab"),
+        probabilities::seed_from_str("ba")
+    );
+
+    // Reproducible sequences from the same label
+    let mut rng1 = probabilities::MinstdRng::new(seed);
+    let mut rng2 = probabilities::MinstdRng::new(probabilities::seed_from_str("experiment-1"));
+    for _ in 0..10 {
+        assert_eq!(rng1.gen(), rng2.gen());
+    }
+
+    // Valid seeds for all labels, with few collisions
+    let labels: Vec<String> = (0..1000).map(|i| format!("run-{}", i)).collect();
+    let mut seeds: Vec<u32> = labels
+        .iter()
+        .map(|label| probabilities::seed_from_str(label))
+        .collect();
+    seeds.push(probabilities::seed_from_str(""));
+    assert!(seeds.iter().all(|&seed| seed != 0 && seed < 0x7FFFFFFF));
+    seeds.sort();
+    seeds.dedup();
+    assert_eq!(seeds.len(), 1001);
+}
+
+#[test]
+fn test_rational_decimal_expansion() {
+    let expansion = |n: i64, d: i64| rational::Rational::new(n, d).decimal_expansion(100);
+    let some = |block: &str| Some(block.to_string());
+
+    assert_eq!(expansion(1, 3), ("0.".to_string(), some("3")));
+    assert_eq!(expansion(1, 4), ("0.25".to_string(), None));
+    assert_eq!(expansion(1, 7), ("0.".to_string(), some("142857")));
+    assert_eq!(expansion(1, 6), ("0.1".to_string(), some("6")));
+    assert_eq!(expansion(-22, 7), ("-3.".to_string(), some("142857")));
+    assert_eq!(expansion(3, 1), ("3".to_string(), None));
+    assert_eq!(expansion(0, 5), ("0".to_string(), None));
+
+    // Period 96 of 1/97 exceeds max_digits: truncated expansion
+    let r = rational::Rational::new(1i64, 97i64);
+    assert_eq!(r.decimal_expansion(5), ("0.01030".to_string(), None));
+    assert_eq!(r.decimal_expansion(96).1.unwrap().len(), 96);
+
+    let r = rational::Rational::new(6i64, -4i64);
+    assert_eq!((r.numerator(), r.denominator()), (-3, 2));
+}
+
+#[test]
+fn test_rational_continued_fraction() {
+    let r = rational::Rational::new(43i64, 30i64);
+    assert_eq!(r.continued_fraction(), vec![1, 2, 3, 4]);
+    assert_eq!(
+        rational::Rational::new(5i64, 1i64).continued_fraction(),
+        vec![5]
+    );
+    assert_eq!(
+        rational::Rational::new(-7i64, 3i64).continued_fraction(),
+        vec![-3, 1, 2]
+    );
+
+    // Round trip: fold the coefficients from the back, x = a_i + 1 / x
+    for (n, d) in [
+        (43i64, 30i64),
+        (-7, 3),
+        (355, 113),
+        (1, 1000),
+        (0, 1),
+        (-1, 2),
+    ] {
+        let r = rational::Rational::new(n, d);
+        let coefficients = r.continued_fraction();
+        let (last, rest) = coefficients.split_last().unwrap();
+        let rebuilt = rest
+            .iter()
+            .rev()
+            .fold(rational::Rational::new(*last, 1), |x, &a| {
+                rational::Rational::new(a, 1) + rational::Rational::new(1, 1) / x
+            });
+        assert_eq!(rebuilt, r);
+    }
+}
+
+#[test]
+fn test_probabilities_estimate_pi_monte_carlo() {
+    let estimate = probabilities::estimate_pi_monte_carlo(1_000_000, 42);
+    assert!((estimate - std::f64::consts::PI).abs() < 0.01);
+    assert_eq!(
+        estimate,
+        probabilities::estimate_pi_monte_carlo(1_000_000, 42)
+    );
+}
+
+#[test]
+fn test_probabilities_empirical_cdf() {
+    let data = [4., 2., 1., 3.];
+    assert_eq!(probabilities::empirical_cdf(&data, 2.5), 0.5);
+    assert_eq!(probabilities::empirical_cdf(&data, 2.), 0.5);
+    assert_eq!(probabilities::empirical_cdf(&data, 0.), 0.);
+    assert_eq!(probabilities::empirical_cdf(&data, 4.), 1.);
+
+    assert_eq!(
+        probabilities::empirical_cdf_curve(&data),
+        vec![(1., 0.25), (2., 0.5), (3., 0.75), (4., 1.)]
+    );
+    assert_eq!(
+        probabilities::empirical_cdf_curve(&[2., 1., 2., 2.]),
+        vec![(1., 0.25), (2., 1.)]
+    );
+    assert!(probabilities::empirical_cdf_curve(&[]).is_empty());
+
+    // Box-Muller samples: about half below the mean, about 84% below one standard deviation
+    let samples = probabilities::box_muller(100_000, 7);
+    assert!((probabilities::empirical_cdf(&samples, 0.) - 0.5).abs() < 0.01);
+    assert!((probabilities::empirical_cdf(&samples, 1.) - 0.8413).abs() < 0.01);
+}
+
+#[test]
+fn test_files_find_similar_filenames() {
+    let dir = std::env::temp_dir().join("rust_algorithms_test_fuzzy_search");
+    std::fs::create_dir_all(&dir).unwrap();
+    for name in ["maze1.txt", "maze2.txt", "maze12.txt", "readme.md"] {
+        std::fs::write(dir.join(name), "").unwrap();
+    }
+    let dir_path = dir.to_string_lossy().to_string();
+    let file_names = |paths: Vec<String>| -> Vec<String> {
+        paths
+            .iter()
+            .map(|path| {
+                std::path::Path::new(path)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    };
+
+    assert_eq!(
+        file_names(files::find_similar_filenames(&dir_path, "maze1", 1)),
+        vec!["maze1.txt", "maze12.txt", "maze2.txt"]
+    );
+    assert_eq!(
+        file_names(files::find_similar_filenames(&dir_path, "maze1", 0)),
+        vec!["maze1.txt"]
+    );
+    assert_eq!(
+        file_names(files::find_similar_filenames(&dir_path, "reedme", 1)),
+        vec!["readme.md"]
+    );
+    assert!(files::find_similar_filenames(&dir_path, "fractal", 2).is_empty());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_files_hex_dump() {
+    let expected = format!("00000000: 48 65 6c 6c 6f{}  Hello\n", " ".repeat(11 * 3));
+    assert_eq!(files::hex_dump(b"Hello", 16), expected);
+
+    assert_eq!(
+        files::hex_dump(&[0x00, b'A', 0x7f, 0xff, b' ', b'z'], 4),
+        "00000000: 00 41 7f ff  .A..\n00000004: 20 7a         z\n"
+    );
+    assert_eq!(files::hex_dump(&[], 16), "");
+
+    // Offsets of a larger dump
+    let bytes: Vec<u8> = (0..=255).collect();
+    let dump = files::hex_dump(&bytes, 16);
+    let lines: Vec<&str> = dump.lines().collect();
+    assert_eq!(lines.len(), 16);
+    assert!(lines[15].starts_with("000000f0: f0 f1"));
+}
+
+#[test]
+fn test_sorting_variants_sort_options() {
+    use sorting_variants::{SortAlgorithm, SortOptions};
+    let algorithms = [
+        SortAlgorithm::Insertion,
+        SortAlgorithm::Merge,
+        SortAlgorithm::Quick,
+        SortAlgorithm::Heap,
+    ];
+
+    let mut rng = probabilities::MinstdRng::new(99);
+    let input: Vec<i32> = (0..200).map(|_| (rng.gen() % 30) as i32).collect();
+    let mut ascending = input.clone();
+    ascending.sort();
+    let descending: Vec<i32> = ascending.iter().rev().cloned().collect();
+
+    // Stability: one counter per element identifies it (keys have many duplicates)
+    let counters: Vec<Cell<usize>> = input.iter().map(|_| Cell::new(0)).collect();
+    let original_index = |elem: &CountedCmp| {
+        counters
+            .iter()
+            .position(|c| std::ptr::eq(c, elem.counter))
+            .unwrap()
+    };
+
+    for algorithm in algorithms {
+        for descending_order in [false, true] {
+            for stable in [false, true] {
+                let options = SortOptions {
+                    algorithm,
+                    descending: descending_order,
+                    stable,
+                };
+                let mut array = input.clone();
+                sorting_variants::sort(&mut array, options);
+                let expected = if descending_order {
+                    &descending
+                } else {
+                    &ascending
+                };
+                assert_eq!(&array, expected, "{:?}", options);
+
+                if stable || algorithm.is_stable() {
+                    let mut array: Vec<CountedCmp> = input
+                        .iter()
+                        .zip(&counters)
+                        .map(|(&value, counter)| CountedCmp { value, counter })
+                        .collect();
+                    sorting_variants::sort(&mut array, options);
+                    assert!(
+                        array.windows(2).all(|pair| pair[0].value != pair[1].value
+                            || original_index(&pair[0]) < original_index(&pair[1])),
+                        "{:?}",
+                        options
+                    );
+                }
+            }
+        }
+    }
+
+    let mut array = [3, 1, 2];
+    sorting_variants::sort(&mut array, SortOptions::default());
+    assert_eq!(array, [1, 2, 3]);
+
+    // Quick sort on duplicated keys: neither quadratic time nor deep recursion
+    let quick = SortOptions {
+        algorithm: SortAlgorithm::Quick,
+        ..SortOptions::default()
+    };
+    let mut array = vec![0; 50_000];
+    sorting_variants::sort(&mut array, quick);
+    assert!(array.iter().all(|&x| x == 0));
+
+    let input: Vec<i32> = (0..50_000).map(|_| (rng.gen() % 3) as i32).collect();
+    let mut array = input.clone();
+    sorting_variants::sort(&mut array, quick);
+    let mut expected = input;
+    expected.sort();
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn test_sorting_variants_top_k() {
+    assert_eq!(
+        sorting_variants::top_k(0..1000, 5),
+        vec![999, 998, 997, 996, 995]
+    );
+    assert_eq!(
+        sorting_variants::top_k([3, 1, 2].into_iter(), 10),
+        vec![3, 2, 1]
+    );
+    assert!(sorting_variants::top_k(0..10, 0).is_empty());
+    assert_eq!(
+        sorting_variants::top_k([5, 1, 5, 3, 5].into_iter(), 2),
+        vec![5, 5]
+    );
+
+    let mut rng = probabilities::MinstdRng::new(5);
+    let values: Vec<u32> = (0..10_000).map(|_| rng.gen()).collect();
+    let mut sorted = values.clone();
+    sorted.sort_by(|a, b| b.cmp(a));
+    assert_eq!(
+        sorting_variants::top_k(values.into_iter(), 20),
+        sorted[..20].to_vec()
+    );
+}
+
+#[test]
+fn test_classics_fibonacci_checked() {
+    assert_eq!(classics::fibonacci_checked(0), Some(0));
+    assert_eq!(classics::fibonacci_checked(1), Some(1));
+    assert_eq!(classics::fibonacci_checked(6), Some(8));
+    assert_eq!(classics::fibonacci_checked(93), Some(12200160415121876738));
+    assert_eq!(classics::fibonacci_checked(94), None);
+    assert_eq!(classics::fibonacci_checked(u64::MAX), None);
+
+    for n in 0..=93 {
+        assert_eq!(
+            classics::fibonacci_checked(n),
+            Some(classics::fibonacci_iterative(n))
+        );
+    }
+}
+
+#[test]
+fn test_classics_ackermann() {
+    for n in 0..10 {
+        assert_eq!(classics::ackermann(0, n), n + 1);
+        assert_eq!(classics::ackermann(1, n), n + 2);
+        assert_eq!(classics::ackermann(2, n), 2 * n + 3);
+    }
+    assert_eq!(classics::ackermann(2, 3), 9);
+    assert_eq!(classics::ackermann(3, 3), 61);
+    // A(3, n) = 2^(n + 3) - 3: the recursion would be thousands of calls deep
+    assert_eq!(classics::ackermann(3, 10), 8189);
+    assert_eq!(classics::ackermann(4, 0), 13);
+}
+
+#[test]
+fn test_misc_euler_totient() {
+    assert_eq!(misc::prime_factorization(360), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(misc::prime_factorization(97), vec![(97, 1)]);
+    assert!(misc::prime_factorization(1).is_empty());
+    assert_eq!(
+        misc::prime_factorization(1_000_003 * 1_000_003),
+        vec![(1_000_003, 2)]
+    );
+
+    assert_eq!(misc::euler_totient(36), 12);
+    assert_eq!(misc::euler_totient(1), 1);
+    assert_eq!(misc::euler_totient(0), 0);
+    for p in misc::find_primes(0, 200) {
+        assert_eq!(misc::euler_totient(p as u64), p as u64 - 1);
+    }
+
+    // Known values of φ(1..=20)
+    let known: [u64; 20] = [
+        1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4, 12, 6, 8, 8, 16, 6, 18, 8,
+    ];
+    let sieve = misc::euler_totient_sieve(1000);
+    assert_eq!(sieve[0], 0);
+    assert_eq!(&sieve[1..=20], &known);
+    for (n, &totient) in sieve.iter().enumerate() {
+        assert_eq!(misc::euler_totient(n as u64), totient);
+    }
+}
+
+#[test]
+fn test_misc_mod_int() {
+    let a = misc::ModInt::new(7, 10);
+    let b = misc::ModInt::new(8, 10);
+    assert_eq!((a * b).value(), 6);
+    assert_eq!((a + b).value(), 5);
+    assert_eq!((a - b).value(), 9);
+    assert_eq!((b - a).value(), 1);
+    assert_eq!(misc::ModInt::new(23, 10), misc::ModInt::new(3, 10));
+    assert_eq!(misc::ModInt::new(5, 1).value(), 0);
+
+    // No overflow near u64::MAX
+    let m = u64::MAX - 58; // Largest prime below 2^64
+    let x = misc::ModInt::new(u64::MAX - 60, m);
+    assert_eq!((x + x).value(), m - 4);
+    assert_eq!((x * x).value(), 4);
+
+    for (base, exponent, modulus) in [(3, 4, 7), (2, 100, 1_000_000_007), (5, 0, 13), (7, 3, 1)] {
+        assert_eq!(
+            misc::ModInt::new(base, modulus).pow(exponent).value(),
+            misc::mod_pow(base, exponent, modulus)
+        );
+    }
+    assert_eq!(x.pow(m - 1).value(), 1); // Fermat's little theorem
+}
+
+#[test]
+fn test_fractals_to_boustrophedon() {
+    let pixels: Vec<f64> = (0..12).map(|i| i as f64).collect();
+    let serpentine = fractals::to_boustrophedon(&pixels, 4);
+    assert_eq!(
+        serpentine,
+        vec![0., 1., 2., 3., 7., 6., 5., 4., 8., 9., 10., 11.]
+    );
+    assert_eq!(fractals::to_boustrophedon(&serpentine, 4), pixels);
+
+    let params = fractals::FractalParams {
+        width: 16,
+        height: 9,
+        ..fractals::FractalParams::new(0.3, 0.5)
+    };
+    let pixels = fractals::render_fractal(params);
+    let serpentine = fractals::to_boustrophedon(&pixels, 16);
+    for row in 0..9 {
+        let original = &pixels[row * 16..(row + 1) * 16];
+        let reordered = &serpentine[row * 16..(row + 1) * 16];
+        if row % 2 == 1 {
+            assert!(original.iter().eq(reordered.iter().rev()));
+        } else {
+            assert_eq!(original, reordered);
+        }
+    }
+    assert_eq!(fractals::to_boustrophedon(&serpentine, 16), pixels);
+}
+
+#[test]
+fn test_misc_spiral_order() {
+    let square = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+    assert_eq!(misc::spiral_order(&square), vec![1, 2, 3, 6, 9, 8, 7, 4, 5]);
+
+    let wide = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]];
+    assert_eq!(
+        misc::spiral_order(&wide),
+        vec![1, 2, 3, 4, 8, 12, 11, 10, 9, 5, 6, 7]
+    );
+
+    let tall = vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+    assert_eq!(misc::spiral_order(&tall), vec![1, 2, 4, 6, 8, 7, 5, 3]);
+
+    assert_eq!(misc::spiral_order(&[vec![1, 2, 3]]), vec![1, 2, 3]);
+    assert_eq!(
+        misc::spiral_order(&[vec![1], vec![2], vec![3]]),
+        vec![1, 2, 3]
+    );
+    assert!(misc::spiral_order::<i32>(&[]).is_empty());
+    assert!(misc::spiral_order::<i32>(&[vec![], vec![]]).is_empty());
+}
+
+#[test]
+fn test_misc_rotate_matrix_90() {
+    let original = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+    let mut matrix = original.clone();
+    misc::rotate_matrix_90(&mut matrix);
+    assert_eq!(matrix, vec![vec![7, 4, 1], vec![8, 5, 2], vec![9, 6, 3]]);
+
+    for _ in 0..3 {
+        misc::rotate_matrix_90(&mut matrix);
+    }
+    assert_eq!(matrix, original);
+
+    let mut single = vec![vec!['a']];
+    misc::rotate_matrix_90(&mut single);
+    assert_eq!(single, vec![vec!['a']]);
+    let mut empty: Vec<Vec<i32>> = Vec::new();
+    misc::rotate_matrix_90(&mut empty);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_misc_rotate_matrix_90_non_square() {
+    let mut wide = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    misc::rotate_matrix_90(&mut wide);
+}